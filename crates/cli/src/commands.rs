@@ -18,6 +18,14 @@ pub(crate) struct Cli {
     /// Show parse errors.
     #[arg(long, global = true, default_value = "false")]
     pub parsing_errors: bool,
+    /// Don't colour the output, as the `nocolor` config does. Colours are also left out when
+    /// not writing to a terminal.
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    /// Exit with 0 even when violations or parse errors are found, e.g. to only report them in
+    /// CI. Invalid arguments or config still exit with 65.
+    #[arg(long, global = true)]
+    pub nofail: bool,
 }
 
 #[derive(Debug, Subcommand)]
@@ -32,6 +40,17 @@ pub(crate) enum Commands {
         about = "Fix SQL files via passing a list of files or using stdin"
     )]
     Fix(FixArgs),
+    #[command(
+        name = "format",
+        about = "Format SQL files via passing a list of files or using stdin, applying only layout \
+                 and capitalisation fixes"
+    )]
+    Format(FormatArgs),
+    #[command(
+        name = "parse",
+        about = "Parse a SQL file or stdin and print the resulting parse tree"
+    )]
+    Parse(ParseArgs),
     #[command(name = "lsp", about = "Run an LSP server")]
     Lsp,
     #[command(
@@ -60,6 +79,35 @@ pub(crate) struct FixArgs {
     pub force: bool,
     #[arg(default_value_t, short, long)]
     pub format: Format,
+    /// Print the fixes to stdout in this format instead of applying them.
+    #[arg(long, value_enum)]
+    pub patch: Option<PatchFormat>,
+    /// Only apply the fixes of these rules, given as codes, code prefixes, names or groups, e.g.
+    /// `LT,CP`. Errors from the other rules are reported as unfixable.
+    #[arg(long, value_delimiter = ',')]
+    pub fixable: Option<Vec<String>>,
+    /// Also apply fixes which could change the meaning of the query, rather than only those
+    /// changing whitespace or casing.
+    #[arg(long)]
+    pub unsafe_fixes: bool,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct FormatArgs {
+    /// Files or directories to format. Use `-` to read from stdin.
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct ParseArgs {
+    /// File to parse. Use `-` to read from stdin.
+    pub path: PathBuf,
+    /// Only show code segments, hiding whitespace, newlines and comments.
+    #[arg(long)]
+    pub code_only: bool,
+    /// How to print the parse tree.
+    #[arg(default_value_t, short, long)]
+    pub format: ParseFormat,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum, Display)]
@@ -70,6 +118,27 @@ pub(crate) enum Format {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, Default, ValueEnum, Display)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ParseFormat {
+    /// An indented tree with the position of each segment.
+    #[default]
+    Pretty,
+    /// The structure of sqlfluff's parse fixtures, as YAML.
+    Yaml,
+    /// The structure of sqlfluff's parse fixtures, as JSON.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, Display)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum PatchFormat {
+    /// A JSON list of byte ranges to replace in each file.
+    Json,
+    /// A unified diff which can be applied with `git apply`.
+    Diff,
+}
+
 impl Default for Format {
     fn default() -> Self {
         if is_in_github_action() {
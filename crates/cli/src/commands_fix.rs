@@ -1,8 +1,11 @@
 use crate::check_user_input;
 use crate::commands::FixArgs;
 use crate::commands::Format;
-use crate::linter;
+use crate::commands::PatchFormat;
+use crate::{exit_code, linter};
+use sqruff_lib::cli::patches::{json_patches, unified_diff};
 use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::core::Linter;
 use std::path::Path;
 
 pub(crate) fn run_fix(
@@ -15,10 +18,41 @@ pub(crate) fn run_fix(
         paths,
         force,
         format,
+        patch,
+        fixable,
+        unsafe_fixes,
     } = args;
-    let mut linter = linter(config, format, collect_parse_errors);
+    let mut linter = match fix_linter(config, format, fixable, unsafe_fixes, collect_parse_errors) {
+        Ok(linter) => linter,
+        Err(error) => {
+            eprintln!("{error}");
+            return exit_code::USAGE_ERROR;
+        }
+    };
     let result = linter.lint_paths(paths, true, &ignorer);
 
+    if let Some(patch) = patch {
+        let files = result
+            .paths
+            .iter()
+            .flat_map(|path| path.files.iter())
+            .collect::<Vec<_>>();
+        match patch {
+            PatchFormat::Json => println!("{}", json_patches(files.iter().copied())),
+            PatchFormat::Diff => {
+                print!(
+                    "{}",
+                    files
+                        .iter()
+                        .map(|file| unified_diff(file))
+                        .collect::<String>()
+                )
+            }
+        }
+
+        return exit_code::for_violations(files, true);
+    }
+
     if result
         .paths
         .iter()
@@ -30,27 +64,24 @@ pub(crate) fn run_fix(
             .map(|path| path.files.len())
             .sum::<usize>();
         println!("{} files processed, nothing to fix.", count_files);
-        0
+        exit_code::SUCCESS
     } else {
         if !force {
             match check_user_input() {
                 Some(true) => {
                     eprintln!("Attempting fixes...");
                 }
-                Some(false) => return 0,
+                Some(false) => return exit_code::SUCCESS,
                 None => {
                     eprintln!("Invalid input, please enter 'Y' or 'N'");
                     eprintln!("Aborting...");
-                    return 0;
+                    return exit_code::SUCCESS;
                 }
             }
         }
 
-        let any_unfixable_errors = result.paths.iter().any(|path| {
-            path.files
-                .iter()
-                .any(|file| !file.get_violations(Some(false)).is_empty())
-        });
+        let code =
+            exit_code::for_violations(result.paths.iter().flat_map(|path| path.files.iter()), true);
 
         for linted_dir in result.paths {
             for mut file in linted_dir.files {
@@ -61,24 +92,48 @@ pub(crate) fn run_fix(
         }
         linter.formatter_mut().unwrap().completion_message();
 
-        if any_unfixable_errors { 1 } else { 0 }
+        code
     }
 }
 
 pub(crate) fn run_fix_stdin(
     config: FluffConfig,
     format: Format,
+    fixable: Option<Vec<String>>,
+    unsafe_fixes: bool,
     collect_parse_errors: bool,
 ) -> i32 {
     let read_in = crate::stdin::read_std_in().unwrap();
 
-    let linter = linter(config, format, collect_parse_errors);
+    let linter = match fix_linter(config, format, fixable, unsafe_fixes, collect_parse_errors) {
+        Ok(linter) => linter,
+        Err(error) => {
+            eprintln!("{error}");
+            return exit_code::USAGE_ERROR;
+        }
+    };
     let result = linter.lint_string(&read_in, None, true);
 
     // print fixed to std out
-    let violations = result.get_violations(Some(false));
+    let code = exit_code::for_violations([&result], true);
     println!("{}", result.fix_string());
 
-    // if all fixable violations are fixable, return 0 else return 1
-    if violations.is_empty() { 0 } else { 1 }
+    code
+}
+
+fn fix_linter(
+    config: FluffConfig,
+    format: Format,
+    fixable: Option<Vec<String>>,
+    unsafe_fixes: bool,
+    collect_parse_errors: bool,
+) -> Result<Linter, String> {
+    let mut linter = linter(config, format, collect_parse_errors);
+    if unsafe_fixes {
+        linter = linter.unsafe_fixes(true);
+    }
+    match fixable {
+        Some(selectors) => linter.fixable_rules(&selectors),
+        None => Ok(linter),
+    }
 }
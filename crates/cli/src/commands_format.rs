@@ -0,0 +1,87 @@
+use sqruff_lib::cli::formatters::{Formatter, OutputStreamFormatter};
+use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::core::Linter;
+use sqruff_lib::core::linter::linted_file::LintedFile;
+use std::path::Path;
+
+use crate::commands::FormatArgs;
+use crate::exit_code;
+
+pub(crate) fn run_format(
+    args: FormatArgs,
+    config: FluffConfig,
+    ignorer: impl Fn(&Path) -> bool + Send + Sync,
+    collect_parse_errors: bool,
+) -> i32 {
+    let FormatArgs { paths } = args;
+    let formatter = parse_error_formatter(&config);
+    let mut linter = Linter::new(config, None, None, collect_parse_errors).formatting_only();
+    let result = linter.lint_paths(paths, true, &ignorer);
+
+    let mut code = exit_code::SUCCESS;
+    for linted_dir in result.paths {
+        for mut file in linted_dir.files {
+            if dispatch_parse_errors(&formatter, &mut file) {
+                code = exit_code::PARSE_ERRORS;
+            }
+
+            if file.patches.is_empty() {
+                continue;
+            }
+
+            let path = std::mem::take(&mut file.path);
+            if let Err(e) = std::fs::write(&path, file.fix_string()) {
+                eprintln!("{path}: {e}");
+                return exit_code::IO_ERROR;
+            }
+        }
+    }
+
+    code
+}
+
+pub(crate) fn run_format_stdin(config: FluffConfig, collect_parse_errors: bool) -> i32 {
+    let read_in = match crate::stdin::read_std_in() {
+        Ok(read_in) => read_in,
+        Err(e) => {
+            eprintln!("{e}");
+            return exit_code::USAGE_ERROR;
+        }
+    };
+
+    let formatter = parse_error_formatter(&config);
+    let linter = Linter::new(config, None, None, collect_parse_errors).formatting_only();
+    let mut file = linter.lint_string(&read_in, None, true);
+
+    let code = if dispatch_parse_errors(&formatter, &mut file) {
+        exit_code::PARSE_ERRORS
+    } else {
+        exit_code::SUCCESS
+    };
+    print!("{}", file.fix_string());
+
+    code
+}
+
+fn parse_error_formatter(config: &FluffConfig) -> OutputStreamFormatter {
+    OutputStreamFormatter::new(
+        std::io::stderr().into(),
+        config.get("nocolor", "core").as_bool().unwrap_or_default(),
+        config.get("verbose", "core").as_int().unwrap_or_default(),
+    )
+}
+
+/// Shows the errors in `file` which don't come from a rule, i.e. those from
+/// templating, lexing or, with `--parsing-errors`, parsing the file. Returns
+/// whether there were any.
+fn dispatch_parse_errors(formatter: &OutputStreamFormatter, file: &mut LintedFile) -> bool {
+    // Only the layout and capitalisation rules ran, what they found is fixed.
+    file.violations
+        .retain(|violation| violation.rule.is_none() && !violation.ignore && !violation.warning);
+    if file.violations.is_empty() {
+        return false;
+    }
+
+    formatter.dispatch_file_violations(file, false);
+    true
+}
@@ -1,5 +1,5 @@
 use crate::commands::{Format, LintArgs};
-use crate::linter;
+use crate::{exit_code, linter};
 use sqruff_lib::core::config::FluffConfig;
 use std::path::Path;
 
@@ -12,14 +12,13 @@ pub(crate) fn run_lint(
     let LintArgs { paths, format } = args;
     let mut linter = linter(config, format, collect_parse_errors);
 
-    linter.lint_paths(paths, false, &ignorer);
+    let result = linter.lint_paths(paths, false, &ignorer);
 
     linter.formatter().unwrap().completion_message();
-    if linter.formatter().unwrap().has_fail() {
-        1
-    } else {
-        0
-    }
+    exit_code::for_violations(
+        result.paths.iter().flat_map(|path| path.files.iter()),
+        false,
+    )
 }
 
 pub(crate) fn run_lint_stdin(
@@ -30,13 +29,8 @@ pub(crate) fn run_lint_stdin(
     let read_in = crate::stdin::read_std_in().unwrap();
 
     let linter = linter(config, format, collect_parse_errors);
-    linter.lint_string(&read_in, None, false);
+    let result = linter.lint_string(&read_in, None, false);
 
     linter.formatter().unwrap().completion_message();
-
-    if linter.formatter().unwrap().has_fail() {
-        1
-    } else {
-        0
-    }
+    exit_code::for_violations([&result], false)
 }
@@ -0,0 +1,71 @@
+use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::core::Linter;
+use sqruff_lib_core::parser::segments::base::Tables;
+use std::path::Path;
+
+use crate::commands::{ParseArgs, ParseFormat};
+use crate::exit_code;
+
+pub(crate) fn run_parse(args: ParseArgs, config: FluffConfig) -> i32 {
+    let ParseArgs {
+        path,
+        code_only,
+        format,
+    } = args;
+
+    let (sql, filename) = if path == Path::new("-") {
+        (crate::stdin::read_std_in(), None)
+    } else {
+        (
+            std::fs::read_to_string(&path).map_err(|e| e.to_string()),
+            Some(path.display().to_string()),
+        )
+    };
+    let sql = match sql {
+        Ok(sql) => sql,
+        Err(e) => {
+            eprintln!("{e}");
+            return exit_code::USAGE_ERROR;
+        }
+    };
+
+    let linter = Linter::new(config, None, None, true);
+    let tables = Tables::default();
+    let parsed = match linter.parse_string(&tables, &sql, filename) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e.value);
+            return exit_code::PARSE_ERRORS;
+        }
+    };
+
+    if let Some(tree) = &parsed.tree {
+        // The YAML and JSON use the record structure of sqlfluff's parse fixtures,
+        // so that the output can be compared with sqlfluff's.
+        let serialised = tree.to_serialised(code_only, true);
+        match format {
+            ParseFormat::Pretty => print!("{}", tree.tree_repr(code_only)),
+            ParseFormat::Yaml => print!(
+                "{}",
+                serde_yaml::to_string(&serialised.as_sqlfluff_record()).unwrap()
+            ),
+            ParseFormat::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&serialised.as_sqlfluff_record()).unwrap()
+            ),
+        }
+    }
+
+    for violation in &parsed.violations {
+        eprintln!(
+            "L: {:3} | P: {:3} | {}",
+            violation.line_no, violation.line_pos, violation.description
+        );
+    }
+
+    if parsed.violations.is_empty() {
+        exit_code::SUCCESS
+    } else {
+        exit_code::PARSE_ERRORS
+    }
+}
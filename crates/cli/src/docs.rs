@@ -96,7 +96,7 @@ impl From<ErasedRule> for Rule {
             name: value.name(),
             name_no_periods: value.name().replace('.', ""),
             code: value.code(),
-            fixable: value.is_fix_compatible(),
+            fixable: value.is_fixable(),
             description: value.description(),
             long_description: value.long_description(),
             groups: value.groups().iter().map(|g| g.as_ref()).collect(),
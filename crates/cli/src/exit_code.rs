@@ -0,0 +1,35 @@
+use sqruff_lib::core::linter::linted_file::LintedFile;
+
+/// No violations were found, or all of them were fixed.
+pub(crate) const SUCCESS: i32 = 0;
+/// Violations were found which are left in the files.
+pub(crate) const VIOLATIONS: i32 = 1;
+/// A file couldn't be lexed, parsed or templated.
+pub(crate) const PARSE_ERRORS: i32 = 2;
+/// The command line arguments or the config were invalid.
+pub(crate) const USAGE_ERROR: i32 = 65;
+/// A file couldn't be written.
+pub(crate) const IO_ERROR: i32 = 74;
+
+/// The exit code for the violations in `files`, only counting those which
+/// can't be fixed when `unfixable_only` is set. Parse and templating errors
+/// take precedence over rule violations.
+pub(crate) fn for_violations<'a>(
+    files: impl IntoIterator<Item = &'a LintedFile>,
+    unfixable_only: bool,
+) -> i32 {
+    let mut code = SUCCESS;
+    for file in files {
+        for violation in file.get_violations(unfixable_only.then_some(false)) {
+            if violation.ignore || violation.warning {
+                continue;
+            }
+            // Only errors from outside the rules, e.g. unparsable sections, have no rule.
+            if violation.rule.is_none() {
+                return PARSE_ERRORS;
+            }
+            code = VIOLATIONS;
+        }
+    }
+    code
+}
@@ -26,9 +26,47 @@ impl IgnoreFile {
         }
     }
 
-    /// Check if the given path should be ignored.
+    /// Check if the given path, or any directory containing it, should be ignored.
     pub(crate) fn is_ignored(&self, path: &Path) -> bool {
         let is_dir = path.is_dir();
-        self.ignore.matched(path, is_dir).is_ignore()
+        let relative = path.strip_prefix(self.ignore.path()).unwrap_or(path);
+        // Paths outside the project root can't be matched by its ignore file.
+        if relative.has_root() {
+            return false;
+        }
+        self.ignore
+            .matched_path_or_any_parents(relative, is_dir)
+            .is_ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_file_matches_patterns() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join(IGNORE_FILE_NAME), "build/\n*.gen.sql\n").unwrap();
+        std::fs::create_dir(root.path().join("build")).unwrap();
+
+        let ignore_file = IgnoreFile::new_from_root(root.path()).unwrap();
+
+        assert!(ignore_file.is_ignored(&root.path().join("build")));
+        assert!(ignore_file.is_ignored(&root.path().join("build/a.sql")));
+        assert!(ignore_file.is_ignored(Path::new("build/a.sql")));
+        assert!(ignore_file.is_ignored(Path::new("./build/nested/a.sql")));
+        assert!(ignore_file.is_ignored(&root.path().join("models/a.gen.sql")));
+        assert!(!ignore_file.is_ignored(&root.path().join("models/a.sql")));
+        assert!(!ignore_file.is_ignored(Path::new("/elsewhere/build/a.sql")));
+    }
+
+    #[test]
+    fn test_ignore_file_missing() {
+        let root = tempfile::tempdir().unwrap();
+
+        let ignore_file = IgnoreFile::new_from_root(root.path()).unwrap();
+
+        assert!(!ignore_file.is_ignored(&root.path().join("a.sql")));
     }
 }
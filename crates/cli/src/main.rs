@@ -6,7 +6,7 @@ use sqruff_lib::cli::{
     formatters::OutputStreamFormatter,
     github_annotation_native_formatter::GithubAnnotationNativeFormatter,
 };
-use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::config::{FluffConfig, Value};
 use sqruff_lib::core::linter::core::Linter;
 use std::path::Path;
 use std::sync::Arc;
@@ -18,11 +18,14 @@ use crate::docs::codegen_docs;
 
 mod commands;
 mod commands_fix;
+mod commands_format;
 mod commands_info;
 mod commands_lint;
+mod commands_parse;
 mod commands_rules;
 #[cfg(feature = "codegen-docs")]
 mod docs;
+mod exit_code;
 mod github_action;
 mod ignore;
 mod stdin;
@@ -48,23 +51,50 @@ fn main() {
     #[cfg(feature = "codegen-docs")]
     return codegen_docs();
 
-    let cli = Cli::parse();
+    let cli = Cli::try_parse().unwrap_or_else(|error| {
+        // `--help` and `--version` are also reported as errors, but printed to stdout.
+        let code = if error.use_stderr() {
+            exit_code::USAGE_ERROR
+        } else {
+            exit_code::SUCCESS
+        };
+        _ = error.print();
+        std::process::exit(code);
+    });
     let collect_parse_errors = cli.parsing_errors;
+    let nofail = cli.nofail;
 
-    let config: FluffConfig = if let Some(config) = cli.config.as_ref() {
+    let config = if let Some(config) = cli.config.as_ref() {
         if !Path::new(config).is_file() {
             eprintln!(
                 "The specified config file '{}' does not exist.",
                 cli.config.as_ref().unwrap()
             );
 
-            std::process::exit(1);
+            std::process::exit(exit_code::USAGE_ERROR);
         };
         let read_file = std::fs::read_to_string(config).unwrap();
-        FluffConfig::from_source(&read_file, None)
+        let config = FluffConfig::from_source(&read_file, None);
+        match config.verify_bind_parameter_styles() {
+            Some(error) => Err(error),
+            None => Ok(config),
+        }
     } else {
-        FluffConfig::from_root(None, false, None).unwrap()
+        FluffConfig::from_root(None, false, None)
     };
+    let mut config: FluffConfig = config.unwrap_or_else(|error| {
+        eprintln!("{error}");
+        std::process::exit(exit_code::USAGE_ERROR);
+    });
+    if cli.no_color {
+        config
+            .raw
+            .entry("core".into())
+            .or_insert_with(|| Value::Map(Default::default()))
+            .as_map_mut()
+            .unwrap()
+            .insert("nocolor".into(), Value::Bool(true));
+    }
 
     let current_path = std::env::current_dir().unwrap();
     let ignore_file = ignore::IgnoreFile::new_from_root(&current_path).unwrap();
@@ -78,7 +108,7 @@ fn main() {
         Commands::Lint(args) => match is_std_in_flag_input(&args.paths) {
             Err(e) => {
                 eprintln!("{e}");
-                1
+                exit_code::USAGE_ERROR
             }
             Ok(false) => commands_lint::run_lint(args, config, ignorer, collect_parse_errors),
             Ok(true) => commands_lint::run_lint_stdin(config, args.format, collect_parse_errors),
@@ -86,11 +116,30 @@ fn main() {
         Commands::Fix(args) => match is_std_in_flag_input(&args.paths) {
             Err(e) => {
                 eprintln!("{e}");
-                1
+                exit_code::USAGE_ERROR
             }
             Ok(false) => commands_fix::run_fix(args, config, ignorer, collect_parse_errors),
-            Ok(true) => commands_fix::run_fix_stdin(config, args.format, collect_parse_errors),
+            Ok(true) if args.patch.is_some() => {
+                eprintln!("--patch can't be used when reading from stdin");
+                exit_code::USAGE_ERROR
+            }
+            Ok(true) => commands_fix::run_fix_stdin(
+                config,
+                args.format,
+                args.fixable,
+                args.unsafe_fixes,
+                collect_parse_errors,
+            ),
+        },
+        Commands::Format(args) => match is_std_in_flag_input(&args.paths) {
+            Err(e) => {
+                eprintln!("{e}");
+                exit_code::USAGE_ERROR
+            }
+            Ok(false) => commands_format::run_format(args, config, ignorer, collect_parse_errors),
+            Ok(true) => commands_format::run_format_stdin(config, collect_parse_errors),
         },
+        Commands::Parse(args) => commands_parse::run_parse(args, config),
         Commands::Lsp => {
             sqruff_lsp::run();
             0
@@ -105,6 +154,13 @@ fn main() {
         }
     };
 
+    // Usage errors still fail, `--nofail` is only about what was found in the files.
+    let status_code = if nofail && status_code != exit_code::USAGE_ERROR {
+        exit_code::SUCCESS
+    } else {
+        status_code
+    };
+
     std::process::exit(status_code);
 }
 
@@ -130,7 +186,13 @@ pub(crate) fn linter(config: FluffConfig, format: Format, collect_parse_errors:
         }
     };
 
-    Linter::new(config, Some(formatter), None, collect_parse_errors)
+    // Unlike the library and the language server, the CLI only applies unsafe
+    // fixes when asked to.
+    let unsafe_fixes = config
+        .get("unsafe_fixes", "core")
+        .as_bool()
+        .unwrap_or(false);
+    Linter::new(config, Some(formatter), None, collect_parse_errors).unsafe_fixes(unsafe_fixes)
 }
 
 fn check_user_input() -> Option<bool> {
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+
+fn main() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = cargo_folder.clone();
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let cases: [(&[&str], &str, i32); 10] = [
+        // Clean
+        (&["lint", "-"], "SELECT 1\n", 0),
+        // Violations
+        (&["lint", "-"], "SELECT 1 ,2\n", 1),
+        (&["lint", "--nofail", "-"], "SELECT 1 ,2\n", 0),
+        // Parse errors
+        (&["lint", "--parsing-errors", "-"], "SelEc\n", 2),
+        (&["lint", "--parsing-errors", "--nofail", "-"], "SelEc\n", 0),
+        (&["format", "-"], "SelEc\n", 0),
+        (&["format", "--parsing-errors", "-"], "SelEc\n", 2),
+        (&["format", "-"], "SELECT (\n", 2),
+        // Usage errors, even with `--nofail`
+        (&["lint", "--unknown-flag", "-"], "SELECT 1\n", 65),
+        (&["lint", "--nofail", "-", "file.sql"], "SELECT 1\n", 65),
+    ];
+
+    for (args, stdin, expected) in cases {
+        let mut cmd = Command::new(&sqruff_path);
+        cmd.env("HOME", &cargo_folder);
+        cmd.args(args);
+        cmd.current_dir(&cargo_folder);
+        cmd.write_stdin(stdin);
+
+        let assert = cmd.assert();
+        let output = assert.get_output();
+
+        assert_eq!(output.status.code().unwrap(), expected, "{args:?}");
+    }
+}
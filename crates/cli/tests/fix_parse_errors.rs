@@ -37,7 +37,7 @@ fn parse_errors() {
     assert_eq!(stdout_str, "SelEc\n\n");
     assert_eq!(
         stderr_str,
-        "== [<string>] FAIL\nL:   1 | P:   1 | ???? | Unparsable section\nL:   1 | P:   1 | LT12 | Files must end with a single trailing newline.\n                       | [layout.end_of_file]\n"
+        "== [<string>] FAIL\nL:   1 | P:   1 | ???? | Unparsable section\n     1 | SelEc\n       | ^\nL:   1 | P:   1 | LT12 | Files must end with a single trailing newline.\n                       | [layout.end_of_file]\n     1 | SelEc\n       | ^\n"
     );
-    assert_eq!(output.status.code().unwrap(), 1);
+    assert_eq!(output.status.code().unwrap(), 2);
 }
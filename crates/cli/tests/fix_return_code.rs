@@ -87,7 +87,7 @@ fn fix_return_code() {
     assert_eq!(stdout_str, "SELECT foo AS bar FROM tabs\n");
     assert_eq!(
         stderr_str,
-        "== [<string>] FAIL\nL:   1 | P:  12 | AL02 | Implicit/explicit aliasing of columns.\n                       | [aliasing.column]\n"
+        "== [<string>] FAIL\nL:   1 | P:  12 | AL02 | Implicit/explicit aliasing of columns.\n                       | [aliasing.column]\n     1 | SELECT foo bar FROM tabs\n       |            ^\n"
     );
     assert_eq!(output.status.code().unwrap(), 0);
 
@@ -111,7 +111,34 @@ fn fix_return_code() {
     assert_eq!(stdout_str, "SELECT foo AS bar, * FROM tabs\n");
     assert_eq!(
         stderr_str,
-        "== [<string>] FAIL\nL:   1 | P:   1 | AM04 | Outermost query should produce known number of columns.\n                       | [ambiguous.column_count]\nL:   1 | P:  12 | AL02 | Implicit/explicit aliasing of columns.\n                       | [aliasing.column]\n"
+        "== [<string>] FAIL\nL:   1 | P:   1 | AM04 | Outermost query should produce known number of columns.\n                       | [ambiguous.column_count]\n     1 | SELECT foo bar, * FROM tabs\n       | ^\nL:   1 | P:  12 | AL02 | Implicit/explicit aliasing of columns.\n                       | [aliasing.column]\n     1 | SELECT foo bar, * FROM tabs\n       |            ^\n"
     );
     assert_eq!(output.status.code().unwrap(), 1);
+
+    // STDIN - unsafe fixes are only applied when asked to
+    let config_file = cargo_folder.join("tests/fix_return_code/unsafe_fixes.cfg");
+    for (unsafe_fixes, expected, code) in [
+        (false, "SELECT a FROM t JOIN u USING (id)\n", 1),
+        (true, "SELECT a FROM t JOIN u ON t.id = u.id\n", 0),
+    ] {
+        let mut cmd = Command::new(sqruff_path.clone());
+        cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+        cmd.arg("fix")
+            .arg("-f")
+            .arg("human")
+            .arg("--config")
+            .arg(&config_file);
+        if unsafe_fixes {
+            cmd.arg("--unsafe-fixes");
+        }
+        cmd.arg("-");
+        cmd.write_stdin("SELECT a FROM t JOIN u USING (id)");
+
+        let assert = cmd.assert();
+        let output = assert.get_output();
+
+        let stdout_str = str::from_utf8(&output.stdout).unwrap();
+        assert_eq!(stdout_str, expected);
+        assert_eq!(output.status.code().unwrap(), code);
+    }
 }
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+use std::str;
+
+use assert_cmd::Command;
+use expect_test::expect_file;
+
+fn main() {
+    format_layout_only();
+    format_unparsable();
+}
+
+fn format_layout_only() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = cargo_folder.clone();
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let mut cmd = Command::new(sqruff_path);
+    cmd.env("HOME", &cargo_folder);
+    cmd.arg("format").arg("-");
+    cmd.current_dir(&cargo_folder);
+    // AM02 would add DISTINCT to the UNION, but it isn't a layout rule.
+    cmd.write_stdin("select a  FROM t\nunion select b from u\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+
+    assert_eq!(stdout_str, "select a from t\nunion\nselect b from u\n");
+    assert_eq!(stderr_str, "");
+    assert_eq!(output.status.code().unwrap(), 0);
+}
+
+/// Unparsable files are still formatted, but the parse errors are shown and
+/// fail the command.
+fn format_unparsable() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = cargo_folder.clone();
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let sql_path = cargo_folder.join("tests/format/unparsable.sql");
+
+    let mut cmd = Command::new(sqruff_path);
+    cmd.env("HOME", &cargo_folder);
+    cmd.arg("format").arg("--parsing-errors").arg("-");
+    cmd.current_dir(&cargo_folder);
+    cmd.write_stdin(std::fs::read_to_string(&sql_path).unwrap());
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    let exit_code_str = output.status.code().unwrap().to_string();
+
+    expect_file![sql_path.with_extension("stdout")].assert_eq(stdout_str);
+    expect_file![sql_path.with_extension("stderr")].assert_eq(stderr_str);
+    expect_file![sql_path.with_extension("exitcode")].assert_eq(&exit_code_str);
+}
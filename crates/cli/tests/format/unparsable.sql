@@ -0,0 +1,2 @@
+select a  FROM t
+union selec b frm u
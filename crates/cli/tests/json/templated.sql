@@ -0,0 +1 @@
+SELECT a FROM :tbl  WHERE b = 1
@@ -0,0 +1 @@
+SELECT 1 AS parameter, 2 AS "select"
@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+use std::str;
+
+use assert_cmd::Command;
+
+fn main() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = cargo_folder.clone();
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let mut cmd = Command::new(&sqruff_path);
+    cmd.env("HOME", &cargo_folder);
+    cmd.arg("parse").arg("--code-only").arg("-");
+    cmd.current_dir(&cargo_folder);
+    cmd.write_stdin("SELECT a FROM t;\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    let expected = [
+        "[L:  1, P:  1]      |file:",
+        "[L:  1, P:  1]      |    statement:",
+        "[L:  1, P:  1]      |        select_statement:",
+        "[L:  1, P:  1]      |            select_clause:",
+        "[L:  1, P:  1]      |                keyword:                                      \"SELECT\"",
+        "[L:  1, P:  7]      |                indent:",
+        "[L:  1, P:  8]      |                select_clause_element:",
+        "[L:  1, P:  8]      |                    column_reference:",
+        "[L:  1, P:  8]      |                        naked_identifier:                     \"a\"",
+        "[L:  1, P: 10]      |            dedent:",
+        "[L:  1, P: 10]      |            from_clause:",
+        "[L:  1, P: 10]      |                keyword:                                      \"FROM\"",
+        "[L:  1, P: 15]      |                from_expression:",
+        "[L:  1, P: 15]      |                    indent:",
+        "[L:  1, P: 15]      |                    from_expression_element:",
+        "[L:  1, P: 15]      |                        table_expression:",
+        "[L:  1, P: 15]      |                            table_reference:",
+        "[L:  1, P: 15]      |                                naked_identifier:             \"t\"",
+        "[L:  1, P: 16]      |                    dedent:",
+        "[L:  1, P: 16]      |    statement_terminator:                                     \";\"",
+        "[L:  2, P:  1]      |    end_of_file:",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(stdout_str, expected);
+    assert_eq!(stderr_str, "");
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // Unparsable regions are kept in the tree and reported on stderr.
+    let mut cmd = Command::new(&sqruff_path);
+    cmd.env("HOME", &cargo_folder);
+    cmd.arg("parse")
+        .arg("--code-only")
+        .arg("--format")
+        .arg("yaml")
+        .arg("-");
+    cmd.current_dir(&cargo_folder);
+    cmd.write_stdin("SELECT a FROM t WHERE ]];\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    let expected = [
+        "file:",
+        "  statement:",
+        "    select_statement:",
+        "      select_clause:",
+        "        keyword: SELECT",
+        "        select_clause_element:",
+        "          column_reference:",
+        "            naked_identifier: a",
+        "      from_clause:",
+        "        keyword: FROM",
+        "        from_expression:",
+        "          from_expression_element:",
+        "            table_expression:",
+        "              table_reference:",
+        "                naked_identifier: t",
+        "      unparsable:",
+        "      - word: WHERE",
+        "      - end_square_bracket: ']'",
+        "      - end_square_bracket: ']'",
+        "      - semicolon: ;",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(stdout_str, expected);
+    assert!(stderr_str.contains("Unparsable section"), "{stderr_str}");
+    assert_eq!(output.status.code().unwrap(), 2);
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+use std::str;
+
+use assert_cmd::Command;
+
+fn main() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = cargo_folder.clone();
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let source = std::fs::read_to_string(cargo_folder.join("tests/patch/q.sql")).unwrap();
+
+    let expected = [
+        (
+            "json",
+            r#"[{"file":"tests/patch/q.sql","start":9,"end":25,"replacement":"from t\nwhere b = "}]"#
+                .to_owned()
+                + "\n",
+        ),
+        (
+            "diff",
+            "--- a/tests/patch/q.sql
++++ b/tests/patch/q.sql
+@@ -1,2 +1,2 @@
+-select a  from t
+-where b=1
++select a from t
++where b = 1
+"
+            .to_owned(),
+        ),
+    ];
+
+    for (format, expected) in expected {
+        let mut cmd = Command::new(&sqruff_path);
+        cmd.env("HOME", &cargo_folder);
+        cmd.arg("fix")
+            .arg("--patch")
+            .arg(format)
+            .arg("tests/patch/q.sql");
+        cmd.current_dir(&cargo_folder);
+
+        let assert = cmd.assert();
+        let output = assert.get_output();
+
+        let stdout_str = str::from_utf8(&output.stdout).unwrap();
+
+        assert_eq!(stdout_str, expected, "--patch {format}");
+        assert_eq!(output.status.code().unwrap(), 0);
+    }
+
+    // The patches are only printed, the file is left alone.
+    assert_eq!(
+        std::fs::read_to_string(cargo_folder.join("tests/patch/q.sql")).unwrap(),
+        source
+    );
+}
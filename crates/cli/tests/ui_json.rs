@@ -35,6 +35,12 @@ fn main() {
             cmd.arg(path.to_str().unwrap());
             cmd.arg("-f");
             cmd.arg("json");
+            // Use the fixture's own config when it has one
+            let config_path = path.with_extension("cfg");
+            if config_path.exists() {
+                cmd.arg("--config");
+                cmd.arg(config_path.to_str().unwrap());
+            }
             // Set the HOME environment variable to the fake home directory
             cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
 
@@ -12,6 +12,13 @@ use crate::parser::matchable::Matchable;
 use crate::parser::parsers::StringParser;
 use crate::parser::types::DialectElementType;
 
+/// Which of a dialect's keyword sets a word belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordClass {
+    Reserved,
+    Unreserved,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Dialect {
     pub name: DialectKind,
@@ -148,6 +155,25 @@ impl Dialect {
         self.sets.get(label).cloned().unwrap_or_default()
     }
 
+    /// Classify a word against the dialect's reserved and unreserved keywords,
+    /// ignoring case. Returns `None` if the word isn't a keyword.
+    pub fn keyword_class(&self, word: &str) -> Option<KeywordClass> {
+        let word = word.to_uppercase();
+        let contains = |label| {
+            self.sets
+                .get(label)
+                .is_some_and(|keywords: &AHashSet<&str>| keywords.contains(word.as_str()))
+        };
+
+        if contains("reserved_keywords") {
+            Some(KeywordClass::Reserved)
+        } else if contains("unreserved_keywords") {
+            Some(KeywordClass::Unreserved)
+        } else {
+            None
+        }
+    }
+
     pub fn sets_mut(&mut self, label: &'static str) -> &mut AHashSet<&'static str> {
         assert!(
             label != "bracket_pairs" && label != "angle_bracket_pairs",
@@ -258,6 +284,13 @@ impl Dialect {
         self.lexer = Lexer::new(self.lexer_matchers()).into();
     }
 
+    /// Insert lexer matchers into an already expanded dialect, rebuilding its
+    /// lexer.
+    pub fn extend_lexer(&mut self, lexer_patch: Vec<Matcher>, before: &str) {
+        self.insert_lexer_matchers(lexer_patch, before);
+        self.lexer = Lexer::new(self.lexer_matchers()).into();
+    }
+
     pub fn lexer(&self) -> &Lexer {
         self.lexer.as_ref().unwrap()
     }
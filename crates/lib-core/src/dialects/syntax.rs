@@ -56,6 +56,9 @@ pub enum SyntaxKind {
     TableConstraint,
     JoinOnCondition,
     DatabaseReference,
+    SchemaReference,
+    IndexReference,
+    SequenceReference,
     CollationReference,
     OverClause,
     NamedWindow,
@@ -495,6 +498,8 @@ pub enum SyntaxKind {
     NakedIdentifierAll,
     ProcedureNameIdentifier,
     Parameter,
+    BindParameter,
+    BindParameterMarker,
     DateConstructorLiteral,
     ProcedureOption,
     ExportOption,
@@ -515,6 +520,8 @@ pub enum SyntaxKind {
     JsonOperator,
     At,
     BitStringLiteral,
+    HexLiteral,
+    RawSingleQuote,
     DollarNumericLiteral,
     WidgetNameIdentifier,
     FileKeyword,
@@ -593,6 +600,7 @@ pub enum SyntaxKind {
     MapTypeSchema,
     PrepareStatement,
     ExecuteStatement,
+    DeallocateStatement,
 }
 
 impl SyntaxKind {
@@ -29,6 +29,9 @@ pub struct SQLBaseError {
     pub rule: Option<ErrorStructRule>,
     pub source_slice: Range<usize>,
     pub fixable: bool,
+    /// Whether the error is anchored on code produced by the templater rather
+    /// than on literal source code.
+    pub in_templated_region: bool,
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -49,6 +52,7 @@ impl SQLBaseError {
         self.line_pos = line_pos;
 
         self.source_slice = position_marker.source_slice.clone();
+        self.in_templated_region = !position_marker.is_literal();
     }
 
     pub fn desc(&self) -> &str {
@@ -187,12 +187,17 @@ impl LintFix {
             return result;
         }
 
-        let templated_slices = None;
-        let raw_slices = self.raw_slices_from_templated_slices(
-            templated_file,
-            templated_slices.into_iter(),
-            None,
-        );
+        // Segments in `source` are copied into the edit, so they mustn't come from
+        // templated code either.
+        let templated_slices = self.source.iter().map(|segment| {
+            segment
+                .get_position_marker()
+                .unwrap()
+                .templated_slice
+                .clone()
+        });
+        let raw_slices =
+            self.raw_slices_from_templated_slices(templated_file, templated_slices, None);
         raw_slices.iter().any(|fs| fs.slice_type == "templated")
     }
 }
@@ -228,3 +233,46 @@ impl PartialEq for LintFix {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::syntax::SyntaxKind;
+    use crate::parser::segments::base::SegmentBuilder;
+    use crate::parser::segments::test_functions::raw_segments;
+
+    #[test]
+    fn test_lint_fix_constructors() {
+        let segments = raw_segments();
+        let anchor = segments[0].clone();
+        let positioned = segments[1].clone();
+        let unpositioned = SegmentBuilder::token(0, "baz", SyntaxKind::Word).finish();
+
+        let fix = LintFix::create_before(anchor.clone(), vec![positioned.clone()]);
+        assert_eq!(fix.edit_type, EditType::CreateBefore);
+        assert_eq!(fix.anchor.raw(), "foobar");
+        // Edits are realigned later, so their position markers are dropped.
+        assert_eq!(fix.edit[0].raw(), positioned.raw());
+        assert!(fix.edit[0].get_position_marker().is_none());
+
+        // Only source segments with a position can be traced back to the file.
+        let fix = LintFix::create_after(
+            anchor.clone(),
+            vec![unpositioned.clone()],
+            Some(vec![positioned.clone(), unpositioned.clone()]),
+        );
+        assert_eq!(fix.edit_type, EditType::CreateAfter);
+        assert_eq!(fix.source.len(), 1);
+        assert_eq!(fix.source[0].raw(), positioned.raw());
+
+        let fix = LintFix::replace(anchor.clone(), vec![unpositioned], None);
+        assert_eq!(fix.edit_type, EditType::Replace);
+        assert!(fix.source.is_empty());
+        assert!(!fix.is_just_source_edit());
+
+        let fix = LintFix::delete(anchor.clone());
+        assert_eq!(fix.edit_type, EditType::Delete);
+        assert!(fix.edit.is_empty());
+        assert_eq!(fix, LintFix::delete(anchor));
+    }
+}
@@ -1,6 +1,9 @@
+use std::ops::Range;
+
 use ahash::HashMapExt;
 use rustc_hash::FxHashMap;
 
+use crate::edit_type::EditType;
 use crate::lint_fix::LintFix;
 use crate::segments::AnchorEditInfo;
 
@@ -19,3 +22,57 @@ pub fn compute_anchor_edit_info(
 
     anchor_info
 }
+
+/// Like [`compute_anchor_edit_info`], but holds back fixes which conflict with
+/// an earlier fix. Two edits to the same segment can only be applied together
+/// when they create segments on either side of it, anything else (e.g. two
+/// replacements) would corrupt the tree. Edits to different segments conflict
+/// when one of them replaces or deletes source the other is anchored within,
+/// e.g. replacing a segment and deleting one of its children.
+///
+/// Fixes are considered in order, so the same subset is always applied. The
+/// held back fixes are returned so the caller can retry them on the next loop,
+/// once the others have been applied.
+pub fn compute_non_conflicting_anchor_edit_info(
+    fixes: impl Iterator<Item = LintFix>,
+) -> (FxHashMap<u32, AnchorEditInfo>, Vec<LintFix>) {
+    let mut anchor_info: FxHashMap<u32, AnchorEditInfo> = FxHashMap::new();
+    // The source range of each accepted anchor, and whether it's removed.
+    let mut anchors: Vec<(u32, Range<usize>, bool)> = Vec::new();
+    let mut deferred = Vec::new();
+
+    for fix in fixes {
+        let anchor_id = fix.anchor.id();
+        let range = fix
+            .anchor
+            .get_position_marker()
+            .map(|marker| marker.source_slice.clone());
+        let removes = matches!(fix.edit_type, EditType::Replace | EditType::Delete);
+
+        let overlaps = range.as_ref().is_some_and(|range| {
+            anchors.iter().any(|(id, other, other_removes)| {
+                *id != anchor_id
+                    && range.start < other.end
+                    && other.start < range.end
+                    && ((removes && contains(range, other))
+                        || (*other_removes && contains(other, range)))
+            })
+        });
+
+        let mut info = anchor_info.get(&anchor_id).cloned().unwrap_or_default();
+        info.add(fix.clone());
+
+        if !overlaps && info.is_valid() {
+            anchor_info.insert(anchor_id, info);
+            anchors.extend(range.map(|range| (anchor_id, range, removes)));
+        } else {
+            deferred.push(fix);
+        }
+    }
+
+    (anchor_info, deferred)
+}
+
+fn contains(outer: &Range<usize>, inner: &Range<usize>) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
@@ -1,11 +1,15 @@
 use ahash::AHashMap;
+use nohash_hasher::IntMap;
 use rustc_hash::FxHashMap;
 use smol_str::SmolStr;
 
+use super::match_algorithms::PairedBracket;
 use super::match_result::MatchResult;
 use super::matchable::{Matchable, MatchableCacheKey};
+use super::segments::base::ErasedSegment;
 use crate::dialects::base::Dialect;
 use crate::dialects::syntax::SyntaxKind;
+use crate::errors::SQLParseError;
 use crate::helpers::IndexSet;
 use crate::parser::parser::Parser;
 
@@ -31,13 +35,26 @@ pub struct ParseContext<'a> {
     loc_keys: IndexSet<LocKeyData>,
     parse_cache: FxHashMap<CacheKey, MatchResult>,
     pub(crate) indentation_config: &'a AHashMap<String, bool>,
+    /// Persisting bracket pairs keyed by the opening index, paired up front by
+    /// `pair_persistent_brackets`.
+    pub(crate) bracket_pairs: IntMap<u32, PairedBracket>,
+    /// Maximum nesting of grammar references, or zero for no limit.
+    max_parse_depth: usize,
+    /// Maximum number of grammar references tried per statement, or zero for
+    /// no limit.
+    max_parse_matches: usize,
+    depth: usize,
+    matches: usize,
 }
 
 impl<'a> From<&'a Parser<'a>> for ParseContext<'a> {
     fn from(parser: &'a Parser) -> Self {
         let dialect = parser.dialect();
         let indentation_config = &parser.indentation_config;
-        Self::new(dialect, indentation_config)
+        let mut parse_context = Self::new(dialect, indentation_config);
+        parse_context.max_parse_depth = parser.max_parse_depth;
+        parse_context.max_parse_matches = parser.max_parse_matches;
+        parse_context
     }
 }
 
@@ -49,6 +66,11 @@ impl<'a> ParseContext<'a> {
             loc_keys: IndexSet::default(),
             parse_cache: FxHashMap::default(),
             indentation_config,
+            bracket_pairs: IntMap::default(),
+            max_parse_depth: 0,
+            max_parse_matches: 0,
+            depth: 0,
+            matches: 0,
         }
     }
 
@@ -70,6 +92,57 @@ impl<'a> ParseContext<'a> {
         ret
     }
 
+    /// Run `f` one level deeper in the grammar, aborting with an error instead
+    /// if that would exceed the configured parse depth or match budget.
+    pub(crate) fn guarded<T>(
+        &mut self,
+        segments: &[ErasedSegment],
+        idx: u32,
+        f: impl FnOnce(&mut Self) -> Result<T, SQLParseError>,
+    ) -> Result<T, SQLParseError> {
+        let error = |description: String| SQLParseError {
+            description,
+            segment: segments
+                .get(idx as usize)
+                .or_else(|| segments.last())
+                .cloned(),
+        };
+
+        self.matches += 1;
+
+        if self.max_parse_depth != 0 && self.depth >= self.max_parse_depth {
+            return Err(error(format!(
+                "Maximum parse depth of {} exceeded. The statement is nested too deeply to \
+                 parse; consider raising `max_parse_depth`.",
+                self.max_parse_depth
+            )));
+        }
+
+        if self.max_parse_matches != 0 && self.matches > self.max_parse_matches {
+            return Err(error(format!(
+                "Parsing gave up after {} attempted matches. The statement is too complex to \
+                 parse; consider raising `max_parse_matches`.",
+                self.max_parse_matches
+            )));
+        }
+
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    /// Run `f` with a fresh match budget, restoring the enclosing one after.
+    /// Statements each get their own budget so that long files of simple
+    /// statements aren't rejected as a whole.
+    pub(crate) fn with_fresh_budget<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let matches = std::mem::take(&mut self.matches);
+        let result = f(self);
+        self.matches = matches;
+        result
+    }
+
     fn set_terminators(
         &mut self,
         clear_terminators: bool,
@@ -3,12 +3,12 @@ use itertools::{Itertools, chain};
 use nohash_hasher::IntMap;
 
 use super::sequence::{Bracketed, Sequence};
-use crate::dialects::syntax::{SyntaxKind, SyntaxSet};
+use crate::dialects::syntax::SyntaxSet;
 use crate::errors::SQLParseError;
 use crate::helpers::ToMatchable;
 use crate::parser::context::ParseContext;
 use crate::parser::match_algorithms::{
-    longest_match, skip_start_index_forward_to_code, trim_to_terminator,
+    describe_expected, longest_match, skip_start_index_forward_to_code, trim_to_terminator,
 };
 use crate::parser::match_result::{MatchResult, Matched, Span};
 use crate::parser::matchable::{
@@ -22,6 +22,7 @@ fn parse_mode_match_result(
     current_match: MatchResult,
     max_idx: u32,
     parse_mode: ParseMode,
+    expected: impl FnOnce(&MatchResult) -> Option<String>,
 ) -> MatchResult {
     if parse_mode == ParseMode::Strict {
         return current_match;
@@ -43,7 +44,7 @@ fn parse_mode_match_result(
             start: trim_idx,
             end: max_idx,
         },
-        matched: Matched::SyntaxKind(SyntaxKind::Unparsable).into(),
+        matched: Matched::Unparsable(expected(&current_match)).into(),
         ..MatchResult::default()
     };
 
@@ -137,6 +138,25 @@ impl AnyNumberOf {
     pub fn min_times(&mut self, min_times: usize) {
         self.min_times = min_times;
     }
+
+    /// Describe what could have followed `matched`, for reporting unparsable
+    /// content after it.
+    fn expected_after(
+        &self,
+        matched: &MatchResult,
+        parse_context: &ParseContext,
+    ) -> Option<String> {
+        if !matched.has_match() {
+            return describe_expected(&self.elements, parse_context);
+        }
+
+        let terminators = if self.reset_terminators {
+            self.terminators.clone()
+        } else {
+            chain(self.terminators.clone(), parse_context.terminators.clone()).collect_vec()
+        };
+        describe_expected(&terminators, parse_context)
+    }
 }
 
 impl MatchableTrait for AnyNumberOf {
@@ -200,6 +220,7 @@ impl MatchableTrait for AnyNumberOf {
                     matched,
                     max_idx,
                     self.parse_mode,
+                    |matched| self.expected_after(matched, parse_context),
                 ));
             }
 
@@ -227,6 +248,7 @@ impl MatchableTrait for AnyNumberOf {
                     matched,
                     max_idx,
                     self.parse_mode,
+                    |matched| self.expected_after(matched, parse_context),
                 ));
             }
 
@@ -245,6 +267,7 @@ impl MatchableTrait for AnyNumberOf {
                         matched,
                         max_idx,
                         self.parse_mode,
+                        |matched| self.expected_after(matched, parse_context),
                     ));
                 }
             }
@@ -332,3 +355,109 @@ pub fn any_set_of(elements: Vec<Matchable>) -> AnyNumberOf {
     any_number_of.max_times_per_element = Some(1);
     any_number_of
 }
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::*;
+    use crate::dialects::base::Dialect;
+    use crate::dialects::init::DialectKind;
+    use crate::dialects::syntax::SyntaxKind;
+    use crate::helpers::Config;
+    use crate::parser::parsers::StringParser;
+    use crate::parser::segments::base::Tables;
+    use crate::parser::segments::test_functions::generate_test_segments_func;
+
+    /// Parse mode, options, input length and expected output.
+    type Case = (
+        ParseMode,
+        &'static [&'static str],
+        usize,
+        &'static [&'static str],
+    );
+
+    fn to_tuple(segment: &ErasedSegment) -> String {
+        let kind: &str = segment.get_type().into();
+
+        if segment.segments().is_empty() {
+            format!("{kind}:{}", segment.raw())
+        } else {
+            let children = segment.segments().iter().map(to_tuple).collect::<Vec<_>>();
+            format!("{kind}({})", children.join(", "))
+        }
+    }
+
+    #[test]
+    fn test_parser_grammar_anyof_modes() {
+        let cases: [Case; 8] = [
+            // A single option which matches everything.
+            (ParseMode::Strict, &["a"], 2, &["keyword:a"]),
+            (ParseMode::Greedy, &["a"], 2, &["keyword:a"]),
+            // No option matches.
+            (ParseMode::Strict, &["b"], 2, &[]),
+            (
+                ParseMode::Greedy,
+                &["b"],
+                2,
+                &["unparsable(raw_comparison_operator:a)"],
+            ),
+            // A match with content left over.
+            (ParseMode::Strict, &["a"], 5, &["keyword:a"]),
+            (
+                ParseMode::Greedy,
+                &["a"],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b, whitespace: , raw_comparison_operator:c)",
+                ],
+            ),
+            // Several options, matched repeatedly.
+            (
+                ParseMode::Strict,
+                &["b", "a"],
+                5,
+                &["keyword:a", "whitespace: ", "keyword:b"],
+            ),
+            (
+                ParseMode::Greedy,
+                &["b", "a"],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "keyword:b",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:c)",
+                ],
+            ),
+        ];
+
+        let segments = generate_test_segments_func(vec!["a", " ", "b", " ", "c", "d", " ", "d"]);
+        let dialect = Dialect::default();
+        let indentation_config = AHashMap::new();
+        let tables = Tables::default();
+
+        for (mode, options, end, expected) in cases {
+            let segments = &segments[..end];
+            let any_number_of = AnyNumberOf::new(
+                options
+                    .iter()
+                    .map(|it| StringParser::new(it, SyntaxKind::Keyword).to_matchable())
+                    .collect(),
+            )
+            .config(|this| this.parse_mode = mode);
+
+            let mut ctx = ParseContext::new(&dialect, &indentation_config);
+            let result = any_number_of
+                .match_segments(segments, 0, &mut ctx)
+                .unwrap()
+                .apply(&tables, DialectKind::Ansi, segments);
+            let result = result.iter().map(to_tuple).collect::<Vec<_>>();
+
+            assert_eq!(result, expected, "{mode:?} {options:?}");
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub struct Ref {
     pub exclude: Option<Matchable>,
     terminators: Vec<Matchable>,
     reset_terminators: bool,
+    reset_match_budget: bool,
     pub(crate) allow_gaps: bool,
     pub(crate) optional: bool,
     cache_key: MatchableCacheKey,
@@ -45,6 +46,7 @@ impl Ref {
             exclude: None,
             terminators: Vec::new(),
             reset_terminators: false,
+            reset_match_budget: false,
             allow_gaps: true,
             optional: false,
             cache_key: next_matchable_cache_key(),
@@ -62,6 +64,13 @@ impl Ref {
         self
     }
 
+    /// Give each match of the referenced grammar its own match budget, e.g. so
+    /// that every statement in a file is limited separately.
+    pub fn reset_match_budget(mut self) -> Self {
+        self.reset_match_budget = true;
+        self
+    }
+
     // Method to get the referenced element
     fn _get_elem(&self, dialect: &Dialect) -> Matchable {
         dialect.r#ref(&self.reference)
@@ -78,6 +87,7 @@ impl PartialEq for Ref {
     fn eq(&self, other: &Self) -> bool {
         self.reference == other.reference
             && self.reset_terminators == other.reset_terminators
+            && self.reset_match_budget == other.reset_match_budget
             && self.allow_gaps == other.allow_gaps
             && self.optional == other.optional
     }
@@ -144,9 +154,19 @@ impl MatchableTrait for Ref {
             }
         }
 
-        parse_context.deeper_match(self.reset_terminators, &self.terminators, |this| {
-            elem.match_segments(segments, idx, this)
-        })
+        let match_segments = |this: &mut ParseContext| {
+            this.guarded(segments, idx, |this| {
+                this.deeper_match(self.reset_terminators, &self.terminators, |this| {
+                    elem.match_segments(segments, idx, this)
+                })
+            })
+        };
+
+        if self.reset_match_budget {
+            parse_context.with_fresh_budget(match_segments)
+        } else {
+            match_segments(parse_context)
+        }
     }
 
     fn cache_key(&self) -> MatchableCacheKey {
@@ -118,3 +118,46 @@ impl MatchableTrait for Conditional {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::*;
+    use crate::dialects::base::Dialect;
+    use crate::dialects::syntax::SyntaxKind;
+
+    #[test]
+    fn test_conditional_inserts_meta_when_config_enabled() {
+        let dialect = Dialect::default();
+        // A condition which isn't set in the config counts as disabled.
+        let cases = [
+            (None, Vec::new()),
+            (Some(false), Vec::new()),
+            (Some(true), vec![(3, SyntaxKind::Indent)]),
+        ];
+
+        for (indented_ctes, expected) in cases {
+            let indentation_config = indented_ctes
+                .map(|value| AHashMap::from([("indented_ctes".to_string(), value)]))
+                .unwrap_or_default();
+            let mut parse_context = ParseContext::new(&dialect, &indentation_config);
+
+            let result = Conditional::new(Indent::indent())
+                .indented_ctes()
+                .match_segments(&[], 3, &mut parse_context)
+                .unwrap();
+
+            assert_eq!(result.span, Span { start: 3, end: 3 });
+            assert_eq!(result.insert_segments, expected);
+        }
+
+        // Without any condition the meta is always inserted.
+        let indentation_config = AHashMap::new();
+        let mut parse_context = ParseContext::new(&dialect, &indentation_config);
+        let result = Conditional::new(Indent::dedent())
+            .match_segments(&[], 0, &mut parse_context)
+            .unwrap();
+        assert_eq!(result.insert_segments, [(0, SyntaxKind::Dedent)]);
+    }
+}
@@ -96,13 +96,15 @@ impl MatchableTrait for Delimited {
         let delimiter_matcher = self.delimiter.clone();
 
         let mut terminator_matchers = self.terminators.clone();
-        terminator_matchers.extend(
-            parse_context
-                .terminators
-                .iter()
-                .filter(|&t| &delimiter_matcher != t)
-                .cloned(),
-        );
+        if !self.reset_terminators {
+            terminator_matchers.extend(
+                parse_context
+                    .terminators
+                    .iter()
+                    .filter(|&t| &delimiter_matcher != t)
+                    .cloned(),
+            );
+        }
 
         let delimiter_matchers = &[self.delimiter.clone()];
 
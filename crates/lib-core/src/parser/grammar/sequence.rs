@@ -8,8 +8,8 @@ use crate::errors::SQLParseError;
 use crate::helpers::ToMatchable;
 use crate::parser::context::ParseContext;
 use crate::parser::match_algorithms::{
-    resolve_bracket, skip_start_index_forward_to_code, skip_stop_index_backward_to_code,
-    trim_to_terminator,
+    describe_expected, resolve_bracket, skip_start_index_forward_to_code,
+    skip_stop_index_backward_to_code, trim_to_terminator,
 };
 use crate::parser::match_result::{MatchResult, Matched, Span};
 use crate::parser::matchable::{
@@ -39,6 +39,7 @@ pub struct Sequence {
     pub allow_gaps: bool,
     is_optional: bool,
     pub terminators: Vec<Matchable>,
+    pub reset_terminators: bool,
     cache_key: MatchableCacheKey,
 }
 
@@ -56,6 +57,7 @@ impl Sequence {
             is_optional: false,
             parse_mode: ParseMode::Strict,
             terminators: Vec::new(),
+            reset_terminators: false,
             cache_key: next_matchable_cache_key(),
         }
     }
@@ -77,6 +79,16 @@ impl Sequence {
         self.allow_gaps = allow_gaps;
         self
     }
+
+    /// The terminators which end this sequence, including the parent's unless
+    /// `reset_terminators` is set.
+    fn all_terminators(&self, parse_context: &ParseContext) -> Vec<Matchable> {
+        if self.reset_terminators {
+            self.terminators.clone()
+        } else {
+            [self.terminators.clone(), parse_context.terminators.clone()].concat()
+        }
+    }
 }
 
 impl PartialEq for Sequence {
@@ -131,9 +143,7 @@ impl MatchableTrait for Sequence {
         let mut meta_buffer = Vec::new();
 
         if self.parse_mode == ParseMode::Greedy {
-            let terminators =
-                [self.terminators.clone(), parse_context.terminators.clone()].concat();
-
+            let terminators = self.all_terminators(parse_context);
             max_idx = trim_to_terminator(segments, idx, &terminators, parse_context)?;
         }
 
@@ -165,6 +175,7 @@ impl MatchableTrait for Sequence {
                 }
 
                 insert_segments.extend(meta_buffer.into_iter().map(|meta| (matched_idx, meta)));
+                let expected = describe_expected(std::slice::from_ref(elem), parse_context);
 
                 return Ok(MatchResult {
                     span: Span {
@@ -173,7 +184,7 @@ impl MatchableTrait for Sequence {
                     },
                     insert_segments,
                     child_matches,
-                    matched: Matched::SyntaxKind(SyntaxKind::Unparsable).into(),
+                    matched: Matched::Unparsable(expected).into(),
                 });
             }
 
@@ -194,13 +205,15 @@ impl MatchableTrait for Sequence {
                     return Ok(MatchResult::empty_at(idx));
                 }
 
+                let expected = describe_expected(std::slice::from_ref(elem), parse_context);
+
                 if matched_idx == start_idx {
                     return Ok(MatchResult {
                         span: Span {
                             start: start_idx,
                             end: max_idx,
                         },
-                        matched: Matched::SyntaxKind(SyntaxKind::Unparsable).into(),
+                        matched: Matched::Unparsable(expected).into(),
                         ..MatchResult::default()
                     });
                 }
@@ -210,7 +223,7 @@ impl MatchableTrait for Sequence {
                         start: skip_start_index_forward_to_code(segments, matched_idx, max_idx),
                         end: max_idx,
                     },
-                    matched: Matched::SyntaxKind(SyntaxKind::Unparsable).into(),
+                    matched: Matched::Unparsable(expected).into(),
                     ..MatchResult::default()
                 });
 
@@ -231,8 +244,7 @@ impl MatchableTrait for Sequence {
             matched_idx = elem_match.span.end;
 
             if first_match && self.parse_mode == ParseMode::GreedyOnceStarted {
-                let terminators =
-                    [self.terminators.clone(), parse_context.terminators.clone()].concat();
+                let terminators = self.all_terminators(parse_context);
                 max_idx = trim_to_terminator(segments, matched_idx, &terminators, parse_context)?;
                 first_match = false;
             }
@@ -257,12 +269,14 @@ impl MatchableTrait for Sequence {
             let stop_idx = skip_stop_index_backward_to_code(segments, max_idx, idx);
 
             if stop_idx > idx {
+                let terminators = self.all_terminators(parse_context);
                 child_matches.push(MatchResult {
                     span: Span {
                         start: idx,
                         end: stop_idx,
                     },
-                    matched: Matched::SyntaxKind(SyntaxKind::Unparsable).into(),
+                    matched: Matched::Unparsable(describe_expected(&terminators, parse_context))
+                        .into(),
                     ..Default::default()
                 });
                 matched_idx = stop_idx;
@@ -477,3 +491,225 @@ impl MatchableTrait for Bracketed {
         self.this.cache_key()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::*;
+    use crate::dialects::base::Dialect;
+    use crate::dialects::init::DialectKind;
+    use crate::helpers::Config;
+    use crate::parser::parsers::StringParser;
+    use crate::parser::segments::base::Tables;
+    use crate::parser::segments::test_functions::generate_test_segments_func;
+
+    /// Parse mode, sequence, terminators, input length and expected output.
+    type Case = (
+        ParseMode,
+        &'static [&'static str],
+        &'static [&'static str],
+        usize,
+        &'static [&'static str],
+    );
+
+    fn to_tuple(segment: &ErasedSegment) -> String {
+        let kind: &str = segment.get_type().into();
+
+        if segment.segments().is_empty() {
+            format!("{kind}:{}", segment.raw())
+        } else {
+            let children = segment.segments().iter().map(to_tuple).collect::<Vec<_>>();
+            format!("{kind}({})", children.join(", "))
+        }
+    }
+
+    #[test]
+    fn test_parser_grammar_sequence_modes() {
+        let cases: [Case; 21] = [
+            // Matches where we should get something, and that's the whole sequence.
+            // The trailing whitespace in the slice must not be included.
+            (ParseMode::Strict, &["a"], &[], 2, &["keyword:a"]),
+            (ParseMode::Greedy, &["a"], &[], 2, &["keyword:a"]),
+            (ParseMode::GreedyOnceStarted, &["a"], &[], 2, &["keyword:a"]),
+            // Running out of segments before matching the whole sequence.
+            (ParseMode::Strict, &["a", "b"], &[], 2, &[]),
+            (
+                ParseMode::Greedy,
+                &["a", "b"],
+                &[],
+                2,
+                &["unparsable(keyword:a)"],
+            ),
+            (
+                ParseMode::GreedyOnceStarted,
+                &["a", "b"],
+                &[],
+                2,
+                &["unparsable(keyword:a)"],
+            ),
+            // Failing to match the first element.
+            (ParseMode::Strict, &["b"], &[], 2, &[]),
+            (ParseMode::GreedyOnceStarted, &["b"], &[], 2, &[]),
+            (
+                ParseMode::Greedy,
+                &["b"],
+                &[],
+                2,
+                &["unparsable(raw_comparison_operator:a)"],
+            ),
+            // Matching the whole sequence with content left over.
+            (ParseMode::Strict, &["a"], &[], 5, &["keyword:a"]),
+            (
+                ParseMode::Greedy,
+                &["a"],
+                &[],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b, whitespace: , raw_comparison_operator:c)",
+                ],
+            ),
+            (
+                ParseMode::GreedyOnceStarted,
+                &["a"],
+                &[],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b, whitespace: , raw_comparison_operator:c)",
+                ],
+            ),
+            // As above, but stopping at a terminator. The whitespace before it is not claimed.
+            (ParseMode::Strict, &["a"], &["c"], 5, &["keyword:a"]),
+            (
+                ParseMode::Greedy,
+                &["a"],
+                &["c"],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b)",
+                ],
+            ),
+            (
+                ParseMode::GreedyOnceStarted,
+                &["a"],
+                &["c"],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b)",
+                ],
+            ),
+            // Matching the first element but not the second, with terminators.
+            (ParseMode::Strict, &["a", "x"], &["c"], 5, &[]),
+            (
+                ParseMode::Greedy,
+                &["a", "x"],
+                &["c"],
+                5,
+                &[
+                    "keyword:a",
+                    "whitespace: ",
+                    "unparsable(raw_comparison_operator:b)",
+                ],
+            ),
+            // Competition between sequence elements and terminators.
+            (
+                ParseMode::GreedyOnceStarted,
+                &["a"],
+                &["a"],
+                2,
+                &["keyword:a"],
+            ),
+            (ParseMode::Greedy, &["a"], &["a"], 2, &[]),
+            (
+                ParseMode::GreedyOnceStarted,
+                &["a", "b"],
+                &["b"],
+                3,
+                &["unparsable(keyword:a)"],
+            ),
+            (
+                ParseMode::Greedy,
+                &["a", "b"],
+                &["b"],
+                3,
+                &["unparsable(keyword:a)"],
+            ),
+        ];
+
+        let segments = generate_test_segments_func(vec!["a", " ", "b", " ", "c", "d", " ", "d"]);
+        let dialect = Dialect::default();
+        let indentation_config = AHashMap::new();
+        let tables = Tables::default();
+
+        for (mode, sequence, terminators, end, expected) in cases {
+            let segments = &segments[..end];
+            let sequence = Sequence::new(
+                sequence
+                    .iter()
+                    .map(|it| StringParser::new(it, SyntaxKind::Keyword).to_matchable())
+                    .collect(),
+            )
+            .config(|this| {
+                this.parse_mode(mode);
+                this.terminators = terminators
+                    .iter()
+                    .map(|it| StringParser::new(it, SyntaxKind::Keyword).to_matchable())
+                    .collect();
+            });
+
+            let mut ctx = ParseContext::new(&dialect, &indentation_config);
+            let result = sequence
+                .match_segments(segments, 0, &mut ctx)
+                .unwrap()
+                .apply(&tables, DialectKind::Ansi, segments);
+            let result = result.iter().map(to_tuple).collect::<Vec<_>>();
+
+            assert_eq!(
+                result, expected,
+                "{mode:?} {sequence:?} terminated by {terminators:?}"
+            );
+        }
+    }
+    #[test]
+    fn test_parser_grammar_sequence_reset_terminators() {
+        let keyword = |raw| StringParser::new(raw, SyntaxKind::Keyword).to_matchable();
+        let segments = generate_test_segments_func(vec!["a", " ", "b", " ", "c", " ", "d"]);
+        let dialect = Dialect::default();
+        let indentation_config = AHashMap::new();
+
+        // The parent terminates at `c`, the sequence itself at `d`.
+        for (reset_terminators, unparsable, expected) in [
+            (false, Span { start: 2, end: 3 }, "C or D"),
+            (true, Span { start: 2, end: 5 }, "D"),
+        ] {
+            let sequence = Sequence::new(vec![keyword("a")]).config(|this| {
+                this.parse_mode(ParseMode::Greedy);
+                this.terminators = vec![keyword("d")];
+                this.reset_terminators = reset_terminators;
+            });
+
+            let mut ctx = ParseContext::new(&dialect, &indentation_config);
+            let result = ctx
+                .deeper_match(false, &[keyword("c")], |ctx| {
+                    sequence.match_segments(&segments, 0, ctx)
+                })
+                .unwrap();
+
+            let child = result.child_matches.last().unwrap();
+            assert_eq!(child.span, unparsable, "reset: {reset_terminators}");
+            assert!(
+                matches!(&child.matched, Some(Matched::Unparsable(Some(it))) if it == expected),
+                "reset: {reset_terminators}, matched: {:?}",
+                child.matched
+            );
+        }
+    }
+}
@@ -662,18 +662,23 @@ fn iter_segments(
             }
 
             if tfs.slice_type == "literal" {
-                let tfs_offset = tfs.source_slice.start - tfs.templated_slice.start;
+                // Templated code may be longer or shorter than its source, so the
+                // offset between the two can go either way.
+                let tfs_offset =
+                    tfs.source_slice.start as isize - tfs.templated_slice.start as isize;
+                let to_source =
+                    |templated_idx: usize| templated_idx.wrapping_add_signed(tfs_offset);
 
                 // NOTE: Greater than OR EQUAL, to include the case of it matching
                 // length exactly.
                 if element.template_slice.end <= tfs.templated_slice.end {
                     let slice_start = stashed_source_idx.unwrap_or_else(|| {
-                        element.template_slice.start + consumed_element_length + tfs_offset
+                        to_source(element.template_slice.start + consumed_element_length)
                     });
 
                     result.push(element.to_segment(
                         PositionMarker::new(
-                            slice_start..element.template_slice.end + tfs_offset,
+                            slice_start..to_source(element.template_slice.end),
                             element.template_slice.clone(),
                             templated_file.clone(),
                             None,
@@ -717,8 +722,8 @@ fn iter_segments(
 
                         result.push(element.to_segment(
                             PositionMarker::new(
-                                element.template_slice.start + consumed_element_length + tfs_offset
-                                    ..tfs.templated_slice.end + tfs_offset,
+                                to_source(element.template_slice.start + consumed_element_length)
+                                    ..to_source(tfs.templated_slice.end),
                                 element.template_slice.clone(),
                                 templated_file.clone(),
                                 None,
@@ -912,4 +917,31 @@ mod tests {
         assert_eq!(res.elements.len(), 5);
         assert_eq!(res.elements[2].text, "#..#");
     }
+
+    #[test]
+    fn test_parser_lexer_end_of_file() {
+        let matchers: Vec<Matcher> = vec![
+            Matcher::regex("whitespace", r"[^\S\r\n]+", SyntaxKind::Whitespace),
+            Matcher::regex("word", "[a-z]+", SyntaxKind::Word),
+        ];
+        let lexer = Lexer::new(&matchers);
+        let tables = Tables::default();
+
+        for (raw, end) in [("", 0), ("foo bar", 7)] {
+            let (segments, errors) = lexer.lex(&tables, StringOrTemplate::String(raw)).unwrap();
+            assert!(errors.is_empty());
+
+            let last = segments.last().unwrap();
+            assert_eq!(last.get_type(), SyntaxKind::EndOfFile);
+            assert_eq!(last.raw(), "");
+            assert_eq!(last.get_position_marker().unwrap().source_slice, end..end);
+            assert_eq!(
+                segments
+                    .iter()
+                    .filter(|segment| segment.is_type(SyntaxKind::EndOfFile))
+                    .count(),
+                1
+            );
+        }
+    }
 }
@@ -17,6 +17,9 @@ use crate::templaters::base::TemplatedFile;
 ///   required.
 /// - Positions in the source and template are with slices and therefore
 ///   identify ranges.
+/// - Slices are byte offsets into the source and templated strings, after the
+///   linter has stripped any byte order mark and normalised line endings, so
+///   fixes can be applied directly by byte range.
 /// - Positions within the fixed file are identified with a line number and line
 ///   position, which identify a point.
 /// - Arithmetic comparisons are on the location in the fixed file.
@@ -152,7 +155,7 @@ impl PositionMarker {
     /// Return the line and position of this marker in the source.
     pub fn source_position(&self) -> (usize, usize) {
         self.templated_file
-            .get_line_pos_of_char_pos(self.templated_slice.start, true)
+            .get_line_pos_of_char_pos(self.source_slice.start, true)
     }
 
     /// Return the line and position of this marker in the source.
@@ -175,9 +178,9 @@ impl PositionMarker {
         (
             line_no + (split.len() - 1),
             if split.len() == 1 {
-                line_pos + raw.len()
+                line_pos + raw.chars().count()
             } else {
-                split.last().unwrap().len() + 1
+                split.last().unwrap().chars().count() + 1
             },
         )
     }
@@ -298,7 +301,7 @@ mod tests {
     use std::ops::Range;
 
     use crate::parser::markers::PositionMarker;
-    use crate::templaters::base::TemplatedFile;
+    use crate::templaters::base::{RawFileSlice, TemplatedFile, TemplatedFileSlice};
 
     /// Test that we can correctly infer positions from strings.
     #[test]
@@ -340,6 +343,16 @@ mod tests {
                 start: 2..2,
                 end: 3..4,
             },
+            Test {
+                raw: "'é'".to_string(),
+                start: 2..2,
+                end: 2..5,
+            },
+            Test {
+                raw: "é\nü€".to_string(),
+                start: 2..2,
+                end: 3..3,
+            },
         ];
 
         for t in tests {
@@ -372,6 +385,38 @@ mod tests {
         assert_eq!(pos.working_loc(), (4, 4))
     }
 
+    /// Test that the source position is found from the source slice, even
+    /// when templating shifts the segment.
+    #[test]
+    fn test_markers_source_position_templated() {
+        let templ = TemplatedFile::new(
+            "SELECT {{ a }} b".to_string(),
+            "<string>".to_string(),
+            Some("SELECT 100000000 b".to_string()),
+            Some(vec![
+                TemplatedFileSlice::new("literal", 0..7, 0..7),
+                TemplatedFileSlice::new("templated", 7..14, 7..16),
+                TemplatedFileSlice::new("literal", 14..16, 16..18),
+            ]),
+            Some(vec![
+                RawFileSlice::new("SELECT ".to_string(), "literal".to_string(), 0, None, None),
+                RawFileSlice::new(
+                    "{{ a }}".to_string(),
+                    "templated".to_string(),
+                    7,
+                    None,
+                    None,
+                ),
+                RawFileSlice::new(" b".to_string(), "literal".to_string(), 14, None, None),
+            ]),
+        )
+        .unwrap();
+
+        let pos = PositionMarker::new(15..16, 17..18, templ, None, None);
+        assert_eq!(pos.source_position(), (1, 16));
+        assert_eq!(pos.templated_position(), (1, 18));
+    }
+
     /// Test that we can correctly compare markers.
     #[test]
     fn test_markers_comparison() {
@@ -1,8 +1,10 @@
 use ahash::AHashMap;
 use itertools::{Itertools as _, enumerate, multiunzip};
+use nohash_hasher::IntMap;
 use smol_str::StrExt;
 
 use super::context::ParseContext;
+use super::grammar::anyof::simple;
 use super::match_result::{MatchResult, Matched, Span};
 use super::matchable::{Matchable, MatchableTrait};
 use super::segments::base::ErasedSegment;
@@ -266,6 +268,22 @@ pub fn resolve_bracket(
         .iter()
         .position(|it| it == &opening_matcher)
         .unwrap();
+
+    let paired_match = if !nested_match && bracket_persists[type_idx] {
+        resolve_paired_bracket(
+            segments,
+            &opening_match,
+            &end_brackets[type_idx],
+            parse_context,
+        )?
+    } else {
+        None
+    };
+
+    if let Some(match_result) = paired_match {
+        return Ok(match_result);
+    }
+
     let mut matched_idx = opening_match.span.end;
     let mut child_matches = vec![opening_match.clone()];
 
@@ -274,10 +292,9 @@ pub fn resolve_bracket(
         let (match_result, matcher) = next_match(segments, matched_idx, &matchers, parse_context)?;
 
         if !match_result.has_match() {
-            return Err(SQLParseError {
-                description: "Couldn't find closing bracket for opening bracket.".into(),
-                segment: segments[opening_match.span.start as usize].clone().into(),
-            });
+            return Err(unclosed_bracket_error(
+                &segments[opening_match.span.start as usize],
+            ));
         }
 
         let matcher = matcher.unwrap();
@@ -336,6 +353,153 @@ pub fn resolve_bracket(
     }
 }
 
+/// Report a bracket which was never closed, pointing back at where it was
+/// opened so the user doesn't have to hunt for it from the end of the file.
+fn unclosed_bracket_error(opening: &ErasedSegment) -> SQLParseError {
+    let description = match opening.get_position_marker() {
+        Some(marker) => format!(
+            "Unclosed bracket '{}' opened at line {}, position {}.",
+            opening.raw(),
+            marker.line_no(),
+            marker.line_pos()
+        ),
+        None => format!("Unclosed bracket '{}'.", opening.raw()),
+    };
+
+    SQLParseError {
+        description,
+        segment: opening.clone().into(),
+    }
+}
+
+/// A persisting bracket pair found by `pair_persistent_brackets`.
+#[derive(Debug, Clone)]
+pub(crate) struct PairedBracket {
+    opening: ErasedSegment,
+    closing_idx: u32,
+    closing: ErasedSegment,
+}
+
+/// Pair up the persisting brackets of the dialect's `bracket_pairs` ahead of
+/// parsing, so that `resolve_bracket` can reuse the pairing rather than
+/// rescanning the bracketed content every time a `Bracketed` grammar is tried.
+///
+/// All bracket types share one stack, so a pair is only recorded when
+/// everything inside it is balanced too. If any closing bracket doesn't match
+/// the innermost open one, nothing is paired and `resolve_bracket` reports
+/// the mismatch as usual once the parser reaches it.
+pub(crate) fn pair_persistent_brackets(
+    segments: &[ErasedSegment],
+    parse_context: &mut ParseContext,
+) -> Result<IntMap<u32, PairedBracket>, SQLParseError> {
+    let dialect = parse_context.dialect();
+    let brackets = dialect
+        .bracket_sets("bracket_pairs")
+        .into_iter()
+        .map(|(_, start_ref, end_ref, persists)| {
+            (
+                dialect.grammar(start_ref),
+                dialect.grammar(end_ref),
+                persists,
+            )
+        })
+        .collect_vec();
+
+    let mut pairs = IntMap::default();
+    let mut open_brackets: Vec<(usize, u32)> = Vec::new();
+
+    'segments: for idx in 0..segments.len() as u32 {
+        for (type_idx, (start_bracket, end_bracket, persists)) in brackets.iter().enumerate() {
+            if start_bracket
+                .match_segments(segments, idx, parse_context)?
+                .has_match()
+            {
+                open_brackets.push((type_idx, idx));
+                continue 'segments;
+            }
+
+            if end_bracket
+                .match_segments(segments, idx, parse_context)?
+                .has_match()
+            {
+                let Some((open_type_idx, open_idx)) = open_brackets.pop() else {
+                    return Ok(IntMap::default());
+                };
+
+                if open_type_idx != type_idx {
+                    return Ok(IntMap::default());
+                }
+
+                if *persists {
+                    pairs.insert(
+                        open_idx,
+                        PairedBracket {
+                            opening: segments[open_idx as usize].clone(),
+                            closing_idx: idx,
+                            closing: segments[idx as usize].clone(),
+                        },
+                    );
+                }
+                continue 'segments;
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// Resolve a persisting bracket from the pairs found by
+/// `pair_persistent_brackets`.
+///
+/// The pairs are indexed against the segments of the whole file, so they are
+/// only used when both brackets are the very segments that were paired.
+/// Anything else, such as parsing a slice of segments built elsewhere, falls
+/// back to scanning for the closing bracket.
+fn resolve_paired_bracket(
+    segments: &[ErasedSegment],
+    opening_match: &MatchResult,
+    end_bracket: &Matchable,
+    parse_context: &mut ParseContext,
+) -> Result<Option<MatchResult>, SQLParseError> {
+    let Some(pair) = parse_context.bracket_pairs.get(&opening_match.span.start) else {
+        return Ok(None);
+    };
+
+    let closing_idx = pair.closing_idx;
+    let is_paired = |idx: u32, paired: &ErasedSegment| {
+        segments
+            .get(idx as usize)
+            .is_some_and(|segment| segment.is(paired))
+    };
+
+    if !is_paired(opening_match.span.start, &pair.opening) || !is_paired(closing_idx, &pair.closing)
+    {
+        return Ok(None);
+    }
+
+    let closing_match = end_bracket.match_segments(segments, closing_idx, parse_context)?;
+    if !closing_match.has_match() {
+        return Ok(None);
+    }
+
+    let match_result = MatchResult {
+        span: Span {
+            start: opening_match.span.start,
+            end: closing_match.span.end,
+        },
+        matched: None,
+        insert_segments: vec![
+            (opening_match.span.end, SyntaxKind::Indent),
+            (closing_match.span.start, SyntaxKind::Dedent),
+        ],
+        child_matches: vec![opening_match.clone(), closing_match],
+    };
+
+    Ok(Some(
+        match_result.wrap(Matched::SyntaxKind(SyntaxKind::Bracketed)),
+    ))
+}
+
 type BracketMatch = Result<(MatchResult, Option<Matchable>, Vec<MatchResult>), SQLParseError>;
 
 fn next_ex_bracket_match(
@@ -545,3 +709,236 @@ pub fn trim_to_terminator(
         idx,
     ))
 }
+
+/// Summarise the tokens which could start a match for any of `elements`, for
+/// use in parse error messages, e.g. `FROM, WHERE or ;`.
+///
+/// Returns `None` if there is nothing to summarise or any of the elements is
+/// too complex to describe by its first token.
+pub fn describe_expected(elements: &[Matchable], parse_context: &ParseContext) -> Option<String> {
+    const MAX_OPTIONS: usize = 8;
+
+    let (raws, types) = simple(elements, parse_context, None)?;
+    let options = raws
+        .into_iter()
+        .sorted()
+        .chain(types.iter().map(|kind| kind.as_str().to_string()))
+        .collect_vec();
+
+    match options.as_slice() {
+        [] => None,
+        [option] => Some(option.clone()),
+        [head @ .., last] if options.len() <= MAX_OPTIONS => {
+            Some(format!("{} or {last}", head.join(", ")))
+        }
+        _ => Some(format!("{}, ...", options[..MAX_OPTIONS].join(", "))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashMap;
+
+    use super::*;
+    use crate::dialects::base::Dialect;
+    use crate::helpers::ToMatchable;
+    use crate::parser::parsers::StringParser;
+    use crate::parser::segments::test_functions::generate_test_segments_func;
+
+    fn bracket_dialect() -> Dialect {
+        let mut dialect = Dialect::new();
+        dialect.add([
+            (
+                "StartBracketSegment".into(),
+                StringParser::new("(", SyntaxKind::StartBracket)
+                    .to_matchable()
+                    .into(),
+            ),
+            (
+                "EndBracketSegment".into(),
+                StringParser::new(")", SyntaxKind::EndBracket)
+                    .to_matchable()
+                    .into(),
+            ),
+            (
+                "StartSquareBracketSegment".into(),
+                StringParser::new("[", SyntaxKind::StartSquareBracket)
+                    .to_matchable()
+                    .into(),
+            ),
+            (
+                "EndSquareBracketSegment".into(),
+                StringParser::new("]", SyntaxKind::EndSquareBracket)
+                    .to_matchable()
+                    .into(),
+            ),
+        ]);
+        dialect.update_bracket_sets(
+            "bracket_pairs",
+            vec![
+                ("round", "StartBracketSegment", "EndBracketSegment", true),
+                (
+                    "square",
+                    "StartSquareBracketSegment",
+                    "EndSquareBracketSegment",
+                    false,
+                ),
+            ],
+        );
+        dialect
+    }
+
+    fn closing_idxs(pairs: &IntMap<u32, PairedBracket>) -> Vec<(u32, u32)> {
+        pairs
+            .iter()
+            .map(|(&open_idx, pair)| (open_idx, pair.closing_idx))
+            .sorted()
+            .collect()
+    }
+
+    /// Resolve the bracket opening at `idx` the way `Bracketed` does, with
+    /// both bracket types in play.
+    fn resolve_at(
+        segments: &[ErasedSegment],
+        idx: u32,
+        parse_context: &mut ParseContext,
+    ) -> Result<MatchResult, SQLParseError> {
+        let dialect = parse_context.dialect();
+        let start_brackets = [
+            dialect.grammar("StartBracketSegment"),
+            dialect.grammar("StartSquareBracketSegment"),
+        ];
+        let end_brackets = [
+            dialect.grammar("EndBracketSegment"),
+            dialect.grammar("EndSquareBracketSegment"),
+        ];
+
+        let (opening_match, opening_matcher) =
+            next_match(segments, idx, &start_brackets, parse_context)?;
+
+        resolve_bracket(
+            segments,
+            opening_match,
+            opening_matcher.unwrap(),
+            &start_brackets,
+            &end_brackets,
+            &[true, false],
+            parse_context,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_pair_persistent_brackets_nested() {
+        let dialect = bracket_dialect();
+        let indentation_config = AHashMap::new();
+        let mut ctx = ParseContext::new(&dialect, &indentation_config);
+
+        let segments = generate_test_segments_func(vec!["(", "[", "(", ")", "]", ")", "(", ")"]);
+        let pairs = pair_persistent_brackets(&segments, &mut ctx).unwrap();
+
+        // Square brackets don't persist, so only the round ones are paired.
+        assert_eq!(closing_idxs(&pairs), [(0, 5), (2, 3), (6, 7)]);
+    }
+
+    #[test]
+    fn test_pair_persistent_brackets_mismatched() {
+        let dialect = bracket_dialect();
+        let indentation_config = AHashMap::new();
+
+        for elems in [
+            vec!["(", "[", ")", "]"],
+            vec!["(", ")", ")", "(", ")"],
+            vec!["(", "]", "(", ")"],
+        ] {
+            let mut ctx = ParseContext::new(&dialect, &indentation_config);
+            let segments = generate_test_segments_func(elems.clone());
+            let pairs = pair_persistent_brackets(&segments, &mut ctx).unwrap();
+
+            assert!(pairs.is_empty(), "{elems:?} paired as {pairs:?}");
+        }
+
+        // With nothing paired the mismatch is still reported when resolving.
+        let mut ctx = ParseContext::new(&dialect, &indentation_config);
+        let segments = generate_test_segments_func(vec!["(", "[", ")", "]"]);
+        ctx.bracket_pairs = pair_persistent_brackets(&segments, &mut ctx).unwrap();
+
+        let err = resolve_at(&segments, 0, &mut ctx).unwrap_err();
+        assert_eq!(err.description, "Found unexpected end bracket!");
+    }
+
+    #[test]
+    fn test_resolve_paired_bracket_matches_scan() {
+        let dialect = bracket_dialect();
+        let indentation_config = AHashMap::new();
+        let segments = generate_test_segments_func(vec!["(", "[", "(", "a", ")", "]", " ", ")"]);
+
+        let mut ctx = ParseContext::new(&dialect, &indentation_config);
+        let scanned = resolve_at(&segments, 0, &mut ctx).unwrap();
+
+        ctx.bracket_pairs = pair_persistent_brackets(&segments, &mut ctx).unwrap();
+        let paired = resolve_at(&segments, 0, &mut ctx).unwrap();
+
+        assert_eq!(paired.span, scanned.span);
+        assert_eq!(paired.insert_segments, scanned.insert_segments);
+        assert_eq!(
+            paired.child_matches[0]
+                .child_matches
+                .iter()
+                .map(|it| it.span)
+                .collect_vec(),
+            scanned.child_matches[0]
+                .child_matches
+                .iter()
+                .map(|it| it.span)
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn test_resolve_paired_bracket_other_segments() {
+        let dialect = bracket_dialect();
+        let indentation_config = AHashMap::new();
+        let mut ctx = ParseContext::new(&dialect, &indentation_config);
+
+        let segments = generate_test_segments_func(vec!["(", "a", "a", "a", "a", ")"]);
+        ctx.bracket_pairs = pair_persistent_brackets(&segments, &mut ctx).unwrap();
+        assert_eq!(closing_idxs(&ctx.bracket_pairs), [(0, 5)]);
+
+        // A closing bracket at the paired index of some other segments must
+        // not be taken as the pair.
+        let other = generate_test_segments_func(vec!["(", ")", "a", "a", "a", ")"]);
+        let resolved = resolve_at(&other, 0, &mut ctx).unwrap();
+        assert_eq!(resolved.span, Span { start: 0, end: 2 });
+    }
+
+    #[test]
+    fn test_longest_match_uses_parse_cache() {
+        let dialect = Dialect::new();
+        let indentation_config = AHashMap::new();
+        let mut parse_context = ParseContext::new(&dialect, &indentation_config);
+        let segments = generate_test_segments_func(vec!["a", " ", "b"]);
+        let matcher = StringParser::new("a", SyntaxKind::Keyword).to_matchable();
+
+        let (result, _) =
+            longest_match(&segments, &[matcher.clone()], 0, &mut parse_context).unwrap();
+        assert_eq!(result.span, Span { start: 0, end: 1 });
+
+        let position = segments[0].get_position_marker().unwrap();
+        let loc_key = parse_context.loc_key((
+            segments[0].raw().clone(),
+            position.working_loc(),
+            segments[0].get_type(),
+            segments.len() as u32,
+        ));
+        let cached = parse_context
+            .check_parse_cache(loc_key, matcher.cache_key())
+            .unwrap();
+        assert_eq!(cached.span, result.span);
+
+        // A repeated attempt at the same position is answered from the cache.
+        parse_context.put_parse_cache(loc_key, matcher.cache_key(), MatchResult::empty_at(0));
+        let (result, _) = longest_match(&segments, &[matcher], 0, &mut parse_context).unwrap();
+        assert!(!result.has_match());
+    }
+}
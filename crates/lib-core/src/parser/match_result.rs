@@ -28,6 +28,9 @@ fn get_point_pos_at_idx(segments: &[ErasedSegment], idx: u32) -> PositionMarker
 pub enum Matched {
     SyntaxKind(SyntaxKind),
     Newtype(SyntaxKind),
+    /// An unparsable region, optionally carrying a description of what the
+    /// grammar expected to find there.
+    Unparsable(Option<String>),
 }
 
 #[derive(Default, Debug, Clone)]
@@ -201,6 +204,19 @@ impl MatchResult {
                         .finish(),
                 ]
             }
+            Matched::Unparsable(expected) => {
+                vec![
+                    SegmentBuilder::node(
+                        tables.next_id(),
+                        SyntaxKind::Unparsable,
+                        dialect,
+                        result_segments,
+                    )
+                    .with_expected(expected)
+                    .position_from_segments()
+                    .finish(),
+                ]
+            }
             Matched::Newtype(kind) => {
                 let old = result_segments.pop().unwrap();
 
@@ -10,6 +10,8 @@ use crate::parser::segments::file::FileSegment;
 pub struct Parser<'a> {
     dialect: &'a Dialect,
     pub(crate) indentation_config: AHashMap<String, bool>,
+    pub(crate) max_parse_depth: usize,
+    pub(crate) max_parse_matches: usize,
 }
 
 impl<'a> From<&'a Dialect> for Parser<'a> {
@@ -17,6 +19,8 @@ impl<'a> From<&'a Dialect> for Parser<'a> {
         Self {
             dialect: value,
             indentation_config: AHashMap::new(),
+            max_parse_depth: 0,
+            max_parse_matches: 0,
         }
     }
 }
@@ -26,9 +30,20 @@ impl<'a> Parser<'a> {
         Self {
             dialect,
             indentation_config,
+            max_parse_depth: 0,
+            max_parse_matches: 0,
         }
     }
 
+    /// Limit how deeply grammar references may nest and how many matches may
+    /// be attempted before parsing is abandoned with an error. Zero disables
+    /// the respective limit.
+    pub fn with_limits(mut self, max_parse_depth: usize, max_parse_matches: usize) -> Self {
+        self.max_parse_depth = max_parse_depth;
+        self.max_parse_matches = max_parse_matches;
+        self
+    }
+
     pub fn dialect(&self) -> &Dialect {
         self.dialect
     }
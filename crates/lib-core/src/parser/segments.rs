@@ -1,5 +1,6 @@
 pub mod base;
 pub mod bracketed;
+pub mod cte;
 pub mod file;
 pub mod fix;
 pub mod from;
@@ -61,6 +61,7 @@ impl SegmentBuilder {
                     source_fixes: vec![],
                     descendant_type_set: Default::default(),
                     raw_segments_with_ancestors: Default::default(),
+                    expected: None,
                 }),
                 hash: OnceCell::new(),
             },
@@ -96,6 +97,13 @@ impl SegmentBuilder {
         self
     }
 
+    pub fn with_expected(mut self, expected: Option<String>) -> Self {
+        if let NodeOrTokenKind::Node(node) = &mut self.node_or_token.kind {
+            node.expected = expected;
+        }
+        self
+    }
+
     pub fn finish(self) -> ErasedSegment {
         ErasedSegment {
             value: Rc::new(self.node_or_token),
@@ -146,6 +154,15 @@ impl ErasedSegment {
         }
     }
 
+    /// For unparsable segments, a description of what the grammar expected to
+    /// find in their place.
+    pub fn expected(&self) -> Option<&str> {
+        match &self.value.kind {
+            NodeOrTokenKind::Node(node) => node.expected.as_deref(),
+            NodeOrTokenKind::Token(_) => None,
+        }
+    }
+
     pub fn get_type(&self) -> SyntaxKind {
         self.value.syntax_kind
     }
@@ -182,6 +199,40 @@ impl ErasedSegment {
         serde_yaml::to_string(&self.to_serialised(code_only, true)).unwrap()
     }
 
+    /// Render the tree as indented text, one segment per line, with its source
+    /// position, type and, for raw segments, its raw.
+    pub fn tree_repr(&self, code_only: bool) -> String {
+        let mut buff = String::new();
+        self.write_tree_repr(&mut buff, 0, code_only);
+        buff
+    }
+
+    fn write_tree_repr(&self, buff: &mut String, indent: usize, code_only: bool) {
+        use std::fmt::Write;
+
+        let position = self.get_position_marker().map_or_else(
+            || "-".to_string(),
+            |marker| format!("[L:{:3}, P:{:3}]", marker.line_no(), marker.line_pos()),
+        );
+        let padded_type = format!("{}{}:", "    ".repeat(indent), self.get_type().as_str());
+        let raw = if self.segments().is_empty() && !self.is_meta() {
+            format!("{:?}", self.raw().as_str())
+        } else {
+            String::new()
+        };
+
+        let line = format!("{position:<20}|{padded_type:<60}  {raw}");
+        _ = writeln!(buff, "{}", line.trim_end());
+
+        for child in self.segments() {
+            if code_only && !child.is_code() && !child.is_meta() {
+                continue;
+            }
+
+            child.write_tree_repr(buff, indent + 1, code_only);
+        }
+    }
+
     pub fn child(&self, seg_types: &SyntaxSet) -> Option<ErasedSegment> {
         self.segments()
             .iter()
@@ -233,6 +284,7 @@ impl ErasedSegment {
                 segments,
             )
             .with_position(self.get_position_marker().unwrap().clone())
+            .with_expected(node.expected.clone())
             .finish(),
             NodeOrTokenKind::Token(_) => self.deep_clone(),
         }
@@ -257,6 +309,7 @@ impl ErasedSegment {
                     source_fixes: node.source_fixes.clone(),
                     descendant_type_set: node.descendant_type_set.clone(),
                     raw_segments_with_ancestors: node.raw_segments_with_ancestors.clone(),
+                    expected: node.expected.clone(),
                 }),
                 hash: OnceCell::new(),
             }),
@@ -343,10 +396,10 @@ impl ErasedSegment {
             .clone()
     }
 
-    pub fn children(
-        &self,
-        seg_types: &'static SyntaxSet,
-    ) -> impl Iterator<Item = &ErasedSegment> + '_ {
+    pub fn children<'a>(
+        &'a self,
+        seg_types: &'a SyntaxSet,
+    ) -> impl Iterator<Item = &'a ErasedSegment> + 'a {
         self.segments()
             .iter()
             .filter(move |seg| seg_types.contains(seg.get_type()))
@@ -810,6 +863,7 @@ impl ErasedSegment {
 
 #[cfg(any(test, feature = "serde"))]
 pub mod serde {
+    use ahash::AHashSet;
     use serde::ser::SerializeMap;
     use serde::{Deserialize, Serialize};
 
@@ -845,6 +899,54 @@ pub mod serde {
         pub fn nested(key: String, segments: Vec<TupleSerialisedSegment>) -> Self {
             Self(key, SerialisedSegmentValue::Nested(segments))
         }
+
+        /// View of the segment in the structure sqlfluff uses for its dialect
+        /// fixtures, so that trees can be compared against that corpus.
+        pub fn as_sqlfluff_record(&self) -> SqlfluffRecord<'_> {
+            SqlfluffRecord(self)
+        }
+    }
+
+    /// A serialised segment in sqlfluff's record structure.
+    ///
+    /// Children are written as a map keyed by segment type, falling back to a
+    /// list of single entry maps only when a type is repeated among siblings.
+    pub struct SqlfluffRecord<'a>(&'a TupleSerialisedSegment);
+
+    impl Serialize for SqlfluffRecord<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry(&self.0.0, &SqlfluffRecordValue(&self.0.1))?;
+            map.end()
+        }
+    }
+
+    struct SqlfluffRecordValue<'a>(&'a SerialisedSegmentValue);
+
+    impl Serialize for SqlfluffRecordValue<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let children = match self.0 {
+                SerialisedSegmentValue::Single(raw) => return serializer.serialize_str(raw),
+                SerialisedSegmentValue::Nested(children) => children,
+            };
+
+            let mut seen = AHashSet::with_capacity(children.len());
+            if children.iter().all(|child| seen.insert(child.0.as_str())) {
+                let mut map = serializer.serialize_map(Some(children.len()))?;
+                for child in children {
+                    map.serialize_entry(&child.0, &SqlfluffRecordValue(&child.1))?;
+                }
+                map.end()
+            } else {
+                serializer.collect_seq(children.iter().map(SqlfluffRecord))
+            }
+        }
     }
 
     impl ErasedSegment {
@@ -999,6 +1101,7 @@ pub struct NodeData {
     source_fixes: Vec<SourceFix>,
     descendant_type_set: OnceCell<SyntaxSet>,
     raw_segments_with_ancestors: OnceCell<Vec<(ErasedSegment, Vec<PathStep>)>>,
+    expected: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1027,7 +1130,13 @@ fn class_types(syntax_kind: SyntaxKind) -> SyntaxSet {
         SyntaxKind::WildcardIdentifier => {
             SyntaxSet::new(&[SyntaxKind::WildcardIdentifier, SyntaxKind::ObjectReference])
         }
-        SyntaxKind::TableReference => SyntaxSet::new(&[SyntaxKind::ObjectReference, syntax_kind]),
+        SyntaxKind::TableReference
+        | SyntaxKind::SchemaReference
+        | SyntaxKind::DatabaseReference
+        | SyntaxKind::IndexReference
+        | SyntaxKind::SequenceReference
+        | SyntaxKind::TriggerReference
+        | SyntaxKind::RoleReference => SyntaxSet::new(&[SyntaxKind::ObjectReference, syntax_kind]),
         _ => SyntaxSet::single(syntax_kind),
     }
 }
@@ -1036,8 +1145,8 @@ fn class_types(syntax_kind: SyntaxKind) -> SyntaxSet {
 mod tests {
     use super::*;
     use crate::lint_fix::LintFix;
-    use crate::linter::compute_anchor_edit_info;
-    use crate::parser::segments::test_functions::{raw_seg, raw_segments};
+    use crate::linter::{compute_anchor_edit_info, compute_non_conflicting_anchor_edit_info};
+    use crate::parser::segments::test_functions::{raw_seg, raw_segments, test_segments};
 
     #[test]
     /// Test comparison of raw segments.
@@ -1074,6 +1183,152 @@ mod tests {
         assert_eq!(raw_seg.raw(), "foobar");
     }
 
+    #[test]
+    /// Test child queries and recursive crawling with pruning.
+    fn test_parser_base_segments_child_queries() {
+        let tables = Tables::default();
+        let segments = test_segments();
+        let expression = SegmentBuilder::node(
+            tables.next_id(),
+            SyntaxKind::Expression,
+            DialectKind::Ansi,
+            segments[2..4].to_vec(),
+        )
+        .position_from_segments()
+        .finish();
+        let statement = SegmentBuilder::node(
+            tables.next_id(),
+            SyntaxKind::Statement,
+            DialectKind::Ansi,
+            vec![
+                segments[0].clone(),
+                segments[1].clone(),
+                expression.clone(),
+                segments[4].clone(),
+            ],
+        )
+        .position_from_segments()
+        .finish();
+
+        let whitespace = SyntaxSet::new(&[SyntaxKind::Whitespace]);
+        assert_eq!(statement.children(&whitespace).count(), 2);
+        assert_eq!(
+            statement.child(&SyntaxSet::new(&[SyntaxKind::Expression])),
+            Some(expression)
+        );
+
+        let operators = SyntaxSet::new(&[SyntaxKind::RawComparisonOperator]);
+        let raws = |crawled: Vec<ErasedSegment>| {
+            crawled
+                .iter()
+                .map(|segment| segment.raw().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            raws(statement.recursive_crawl(&operators, true, &SyntaxSet::EMPTY, true)),
+            ["bar", "foo", "baar"]
+        );
+        assert_eq!(
+            raws(statement.recursive_crawl(
+                &operators,
+                true,
+                &SyntaxSet::new(&[SyntaxKind::Expression]),
+                true
+            )),
+            ["bar"]
+        );
+    }
+
+    #[test]
+    /// Test that cloning a segment shares it rather than copying the tree.
+    fn test_parser_base_segments_clone_shares_tree() {
+        let tables = Tables::default();
+        let segments = test_segments();
+        let statement = SegmentBuilder::node(
+            tables.next_id(),
+            SyntaxKind::Statement,
+            DialectKind::Ansi,
+            segments.clone(),
+        )
+        .position_from_segments()
+        .finish();
+
+        let clone = statement.clone();
+        assert!(clone.is(&statement));
+        assert_eq!(clone.addr(), statement.addr());
+
+        for (child, segment) in statement.segments().iter().zip(&segments) {
+            assert!(child.is(segment));
+        }
+        for (raw, segment) in statement.get_raw_segments().iter().zip(&segments) {
+            assert!(raw.is(segment));
+        }
+    }
+
+    #[test]
+    fn test_parser_base_segments_sqlfluff_record() {
+        use super::serde::TupleSerialisedSegment;
+
+        let leaf = |key: &str, raw: &str| TupleSerialisedSegment::sinlge(key.into(), raw.into());
+        let tree = TupleSerialisedSegment::nested(
+            "file".into(),
+            vec![
+                TupleSerialisedSegment::nested(
+                    "statement".into(),
+                    vec![
+                        leaf("keyword", "SELECT"),
+                        leaf("numeric_literal", "1"),
+                        leaf("comma", ","),
+                        leaf("numeric_literal", "2"),
+                    ],
+                ),
+                leaf("statement_terminator", ";"),
+            ],
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&tree.as_sqlfluff_record()).unwrap(),
+            "file:
+  statement:
+  - keyword: SELECT
+  - numeric_literal: '1'
+  - comma: ','
+  - numeric_literal: '2'
+  statement_terminator: ;
+"
+        );
+    }
+
+    #[test]
+    fn test_parser_base_segments_tree_repr() {
+        let segments = test_segments();
+        let node = SegmentBuilder::node(
+            0,
+            SyntaxKind::File,
+            DialectKind::Ansi,
+            segments[..3].to_vec(),
+        )
+        .position_from_segments()
+        .finish();
+
+        assert_eq!(
+            node.tree_repr(false),
+            "[L:  1, P:  1]      |file:
+[L:  1, P:  1]      |    raw_comparison_operator:                                  \"bar\"
+[L:  1, P:  4]      |    whitespace:                                               \" \\t \"
+[L:  1, P:  7]      |    raw_comparison_operator:                                  \"foo\"
+"
+        );
+        assert_eq!(
+            node.tree_repr(true),
+            "[L:  1, P:  1]      |file:
+[L:  1, P:  1]      |    raw_comparison_operator:                                  \"bar\"
+[L:  1, P:  7]      |    raw_comparison_operator:                                  \"foo\"
+"
+        );
+    }
+
     #[test]
     /// Test BaseSegment.compute_anchor_edit_info().
     fn test_parser_base_segments_compute_anchor_edit_info() {
@@ -1143,4 +1398,77 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_parser_base_segments_compute_non_conflicting_anchor_edit_info() {
+        let raw_segs = raw_segments();
+        let tables = Tables::default();
+        let a = raw_segs[0].edit(tables.next_id(), None, None);
+        let b = raw_segs[1].edit(tables.next_id(), None, None);
+        let new_segment =
+            |raw: &str| SegmentBuilder::token(tables.next_id(), raw, SyntaxKind::Word).finish();
+
+        let fixes = vec![
+            LintFix::create_before(a.clone(), vec![new_segment("x")]),
+            LintFix::replace(b.clone(), vec![new_segment("y")], None),
+            // Can't be combined with the replacement of `b`.
+            LintFix::delete(b.clone()),
+            LintFix::create_after(a.clone(), vec![new_segment("z")], None),
+            // Would be the third edit to `a`.
+            LintFix::replace(a.clone(), vec![new_segment("w")], None),
+        ];
+
+        let (anchor_edit_info, deferred) =
+            compute_non_conflicting_anchor_edit_info(fixes.clone().into_iter());
+
+        assert_eq!(
+            anchor_edit_info[&a.id()].fixes,
+            [fixes[0].clone(), fixes[3].clone()]
+        );
+        assert_eq!(anchor_edit_info[&b.id()].fixes, [fixes[1].clone()]);
+        assert_eq!(deferred, [fixes[2].clone(), fixes[4].clone()]);
+    }
+
+    #[test]
+    fn test_parser_base_segments_compute_non_conflicting_anchor_edit_info_overlap() {
+        let tables = Tables::default();
+        let segments = test_segments()
+            .into_iter()
+            .map(|segment| segment.edit(tables.next_id(), None, None))
+            .collect::<Vec<_>>();
+        let expression = SegmentBuilder::node(
+            tables.next_id(),
+            SyntaxKind::Expression,
+            DialectKind::Ansi,
+            segments[2..4].to_vec(),
+        )
+        .position_from_segments()
+        .finish();
+        let new_segment =
+            |raw: &str| SegmentBuilder::token(tables.next_id(), raw, SyntaxKind::Word).finish();
+
+        let fixes = vec![
+            LintFix::replace(expression.clone(), vec![new_segment("x")], None),
+            // Both anchored inside the replaced expression.
+            LintFix::delete(segments[3].clone()),
+            LintFix::create_before(segments[2].clone(), vec![new_segment("y")]),
+            // Next to it.
+            LintFix::create_before(segments[4].clone(), vec![new_segment("z")]),
+            LintFix::delete(segments[1].clone()),
+        ];
+
+        let (anchor_edit_info, deferred) =
+            compute_non_conflicting_anchor_edit_info(fixes.clone().into_iter());
+
+        assert_eq!(anchor_edit_info[&expression.id()].fixes, [fixes[0].clone()]);
+        assert_eq!(
+            anchor_edit_info[&segments[4].id()].fixes,
+            [fixes[3].clone()]
+        );
+        assert_eq!(
+            anchor_edit_info[&segments[1].id()].fixes,
+            [fixes[4].clone()]
+        );
+        assert_eq!(deferred, [fixes[1].clone(), fixes[2].clone()]);
+    }
 }
@@ -0,0 +1,19 @@
+use crate::dialects::syntax::{SyntaxKind, SyntaxSet};
+use crate::parser::segments::base::ErasedSegment;
+
+pub struct CTEDefinitionSegment(pub ErasedSegment);
+
+impl CTEDefinitionSegment {
+    /// The identifier naming this CTE, e.g. `cte` in `cte (a, b) AS (...)`.
+    pub fn get_identifier(&self) -> Option<ErasedSegment> {
+        self.0.child(
+            const {
+                &SyntaxSet::new(&[
+                    SyntaxKind::NakedIdentifier,
+                    SyntaxKind::QuotedIdentifier,
+                    SyntaxKind::Identifier,
+                ])
+            },
+        )
+    }
+}
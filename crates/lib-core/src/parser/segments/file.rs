@@ -2,6 +2,7 @@ use crate::dialects::init::DialectKind;
 use crate::dialects::syntax::SyntaxKind;
 use crate::errors::SQLParseError;
 use crate::parser::context::ParseContext;
+use crate::parser::match_algorithms::pair_persistent_brackets;
 use crate::parser::matchable::MatchableTrait;
 use crate::parser::segments::base::{ErasedSegment, SegmentBuilder, Tables};
 
@@ -44,6 +45,8 @@ impl FileSegment {
         let final_seg = segments.last().unwrap();
         assert!(final_seg.get_position_marker().is_some());
 
+        parse_context.bracket_pairs = pair_persistent_brackets(segments, parse_context)?;
+
         let file_segment = parse_context.dialect().r#ref("FileSegment");
 
         let match_result = file_segment.match_grammar().unwrap().match_segments(
@@ -75,9 +78,14 @@ impl FileSegment {
 
             matched.extend_from_slice(head);
             matched.push(
-                SegmentBuilder::node(tables.next_id(), SyntaxKind::File, dialect, tail.to_vec())
-                    .position_from_segments()
-                    .finish(),
+                SegmentBuilder::node(
+                    tables.next_id(),
+                    SyntaxKind::Unparsable,
+                    dialect,
+                    tail.to_vec(),
+                )
+                .position_from_segments()
+                .finish(),
             );
             &matched
         } else {
@@ -5,7 +5,7 @@ use crate::parser::segments::fix::SourceFix;
 type LintFixIdx = usize;
 
 /// For a given fix anchor, count of the fix edit types and fixes for it."""
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct AnchorEditInfo {
     pub delete: usize,
     pub replace: usize,
@@ -19,7 +19,6 @@ pub struct AnchorEditInfo {
 
 impl AnchorEditInfo {
     /// Returns total count of fixes.
-    #[allow(dead_code)]
     fn total(&self) -> usize {
         self.delete + self.replace + self.create_before + self.create_after
     }
@@ -29,8 +28,7 @@ impl AnchorEditInfo {
     /// Cases:
     /// * 0-1 fixes of any type: Valid
     /// * 2 fixes: Valid if and only if types are create_before and create_after
-    #[allow(dead_code)]
-    fn is_valid(&self) -> bool {
+    pub(crate) fn is_valid(&self) -> bool {
         let total = self.total();
         if total <= 1 {
             // Definitely valid (i.e. no conflict) if 0 or 1. In practice, this
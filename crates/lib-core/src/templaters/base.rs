@@ -95,6 +95,10 @@ pub struct TemplatedFileInner {
     pub templated_str: Option<String>,
     source_newlines: Vec<usize>,
     templated_newlines: Vec<usize>,
+    /// Byte offsets of the UTF-8 continuation bytes, to count characters
+    /// rather than bytes in line positions.
+    source_continuation_bytes: Vec<usize>,
+    templated_continuation_bytes: Vec<usize>,
     raw_sliced: Vec<RawFileSlice>,
     pub sliced_file: Vec<TemplatedFileSlice>,
 }
@@ -152,6 +156,10 @@ impl TemplatedFileInner {
         let source_newlines: Vec<usize> = iter_indices_of_newlines(source_str.as_str()).collect();
         let templated_newlines: Vec<usize> =
             iter_indices_of_newlines(templated_str.as_str()).collect();
+        let source_continuation_bytes: Vec<usize> =
+            iter_indices_of_continuation_bytes(source_str.as_str()).collect();
+        let templated_continuation_bytes: Vec<usize> =
+            iter_indices_of_continuation_bytes(templated_str.as_str()).collect();
 
         // Consistency check raw string and slices.
         let mut pos = 0;
@@ -221,6 +229,8 @@ impl TemplatedFileInner {
             raw_sliced,
             source_newlines,
             templated_newlines,
+            source_continuation_bytes,
+            templated_continuation_bytes,
             source_str: source_str.clone(),
             sliced_file,
             f_name,
@@ -241,20 +251,23 @@ impl TemplatedFileInner {
     ///
     /// Returns: line_number, line_position
     pub fn get_line_pos_of_char_pos(&self, char_pos: usize, source: bool) -> (usize, usize) {
-        let ref_str = if source {
-            &self.source_newlines
+        let (ref_str, continuation_bytes) = if source {
+            (&self.source_newlines, &self.source_continuation_bytes)
         } else {
-            &self.templated_newlines
+            (&self.templated_newlines, &self.templated_continuation_bytes)
         };
         match ref_str.binary_search(&char_pos) {
             Ok(nl_idx) | Err(nl_idx) => {
-                if nl_idx > 0 {
-                    (nl_idx + 1, char_pos - ref_str[nl_idx - 1])
+                let line_start = if nl_idx > 0 {
+                    ref_str[nl_idx - 1] + 1
                 } else {
-                    // NB: line_pos is char_pos + 1 because character position is 0-indexed,
-                    // but the line position is 1-indexed.
-                    (1, char_pos + 1)
-                }
+                    0
+                };
+                // The position counts characters, so multibyte ones only count once.
+                let multibyte = continuation_bytes.partition_point(|&idx| idx < char_pos)
+                    - continuation_bytes.partition_point(|&idx| idx < line_start);
+                // NB: line_pos is 1-indexed, unlike char_pos.
+                (nl_idx + 1, char_pos - line_start - multibyte + 1)
             }
         }
     }
@@ -528,6 +541,15 @@ pub fn iter_indices_of_newlines(raw_str: &str) -> impl Iterator<Item = usize> +
     raw_str.match_indices('\n').map(|(idx, _)| idx)
 }
 
+/// Byte offsets of the bytes in `raw_str` which continue a multibyte character.
+fn iter_indices_of_continuation_bytes(raw_str: &str) -> impl Iterator<Item = usize> + '_ {
+    raw_str
+        .bytes()
+        .enumerate()
+        .filter(|(_, byte)| byte & 0xC0 == 0x80)
+        .map(|(idx, _)| idx)
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum RawFileSliceType {
     Comment,
@@ -850,6 +872,17 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Line positions count characters, not bytes.
+    fn test_templated_file_get_line_pos_of_char_pos_multibyte() {
+        let tf = TemplatedFile::from("SELECT 'é'\n, 'ü€' AS b");
+
+        // The closing quote of 'é' and the space after 'ü€'.
+        assert_eq!(tf.get_line_pos_of_char_pos(10, true), (1, 10));
+        assert_eq!(tf.get_line_pos_of_char_pos(21, true), (2, 7));
+        assert_eq!(tf.get_line_pos_of_char_pos(21, false), (2, 7));
+    }
+
     #[test]
     fn test_templated_file_find_slice_indices_of_templated_pos() {
         let tests = vec![
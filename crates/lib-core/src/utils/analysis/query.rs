@@ -9,6 +9,7 @@ use crate::dialects::common::AliasInfo;
 use crate::dialects::syntax::{SyntaxKind, SyntaxSet};
 use crate::helpers::IndexMap;
 use crate::parser::segments::base::ErasedSegment;
+use crate::parser::segments::cte::CTEDefinitionSegment;
 use crate::utils::analysis::select::get_select_statement_info;
 use crate::utils::functional::segments::Segments;
 
@@ -377,7 +378,9 @@ impl<T: Default + Clone> Query<'_, T> {
 
         let mut ctes = IndexMap::default();
         for cte in cte_defs {
-            let name_seg = cte.segments()[0].clone();
+            let Some(name_seg) = CTEDefinitionSegment(cte.clone()).get_identifier() else {
+                continue;
+            };
             let name = name_seg.raw().to_uppercase_smolstr();
 
             let queries = cte.recursive_crawl(
@@ -20,7 +20,37 @@ use sqruff_lib_core::vec_of_erased;
 use super::ansi_keywords::{ANSI_RESERVED_KEYWORDS, ANSI_UNRESERVED_KEYWORDS};
 
 pub fn dialect() -> Dialect {
-    raw_dialect().config(|this| this.expand())
+    raw_dialect().config(|this| {
+        // Host variables in embedded SQL, e.g. `WHERE id = :id`. These are parsed
+        // rather than lexed so that the colon of an array slice such as `arr[i:j]`
+        // is left alone.
+        this.add([
+            (
+                "BindParameterSegment".into(),
+                one_of(vec_of_erased![
+                    TypedParser::new(SyntaxKind::BindParameter, SyntaxKind::Parameter),
+                    Ref::new("ColonBindParameterSegment")
+                ])
+                .to_matchable()
+                .into(),
+            ),
+            (
+                "ColonBindParameterSegment".into(),
+                NodeMatcher::new(
+                    SyntaxKind::BindParameter,
+                    Sequence::new(vec_of_erased![
+                        StringParser::new(":", SyntaxKind::BindParameterMarker),
+                        TypedParser::new(SyntaxKind::Word, SyntaxKind::Parameter)
+                    ])
+                    .allow_gaps(false)
+                    .to_matchable(),
+                )
+                .to_matchable()
+                .into(),
+            ),
+        ]);
+        this.expand()
+    })
 }
 
 pub fn raw_dialect() -> Dialect {
@@ -218,6 +248,14 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
+        (
+            // Bind parameters such as `:name` or `@name`. Dialects opt into the
+            // styles they support by adding a lexer matcher for `BindParameter`.
+            "BindParameterSegment".into(),
+            TypedParser::new(SyntaxKind::BindParameter, SyntaxKind::Parameter)
+                .to_matchable()
+                .into(),
+        ),
         (
             "CastOperatorSegment".into(),
             StringParser::new("::", SyntaxKind::CastingOperator)
@@ -423,7 +461,30 @@ pub fn raw_dialect() -> Dialect {
         ),
         (
             "QuotedLiteralSegment".into(),
-            TypedParser::new(SyntaxKind::SingleQuote, SyntaxKind::QuotedLiteral)
+            one_of(vec_of_erased![
+                TypedParser::new(SyntaxKind::SingleQuote, SyntaxKind::QuotedLiteral),
+                TypedParser::new(
+                    SyntaxKind::EscapedSingleQuote,
+                    SyntaxKind::EscapedSingleQuote
+                ),
+                TypedParser::new(
+                    SyntaxKind::UnicodeSingleQuote,
+                    SyntaxKind::UnicodeSingleQuote
+                ),
+                TypedParser::new(SyntaxKind::RawSingleQuote, SyntaxKind::RawSingleQuote)
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "BitStringLiteralSegment".into(),
+            TypedParser::new(SyntaxKind::BitStringLiteral, SyntaxKind::BitStringLiteral)
+                .to_matchable()
+                .into(),
+        ),
+        (
+            "HexLiteralSegment".into(),
+            TypedParser::new(SyntaxKind::HexLiteral, SyntaxKind::HexLiteral)
                 .to_matchable()
                 .into(),
         ),
@@ -595,7 +656,10 @@ pub fn raw_dialect() -> Dialect {
                 Ref::new("DateTimeLiteralGrammar"),
                 Ref::new("ArrayLiteralSegment"),
                 Ref::new("TypedArrayLiteralSegment"),
-                Ref::new("ObjectLiteralSegment")
+                Ref::new("ObjectLiteralSegment"),
+                Ref::new("BitStringLiteralSegment"),
+                Ref::new("HexLiteralSegment"),
+                Ref::new("BindParameterSegment")
             ])
             .to_matchable()
             .into(),
@@ -912,6 +976,40 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
+        (
+            "GeneratedColumnGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("GENERATED"),
+                Ref::keyword("ALWAYS"),
+                Ref::keyword("AS"),
+                Bracketed::new(vec_of_erased![Ref::new("ExpressionSegment")]),
+                one_of(vec_of_erased![
+                    Ref::keyword("STORED"),
+                    Ref::keyword("VIRTUAL")
+                ])
+                .config(|this| this.optional()),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "IdentityGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("GENERATED"),
+                one_of(vec_of_erased![
+                    Ref::keyword("ALWAYS"),
+                    Sequence::new(vec_of_erased![Ref::keyword("BY"), Ref::keyword("DEFAULT")]),
+                ]),
+                Ref::keyword("AS"),
+                Ref::keyword("IDENTITY"),
+                Bracketed::new(vec_of_erased![AnyNumberOf::new(vec_of_erased![Ref::new(
+                    "CreateSequenceOptionsSegment"
+                )])])
+                .config(|this| this.optional()),
+            ])
+            .to_matchable()
+            .into(),
+        ),
         // Base Expression element is the right thing to reference for everything
         // which functions as an expression, but could include literals.
         (
@@ -1236,15 +1334,19 @@ pub fn raw_dialect() -> Dialect {
             "FileSegment".into(),
             NodeMatcher::new(
                 SyntaxKind::File,
-                Delimited::new(vec![Ref::new("StatementSegment").to_matchable()])
-                    .config(|this| {
-                        this.allow_trailing();
-                        this.delimiter(
-                            AnyNumberOf::new(vec![Ref::new("DelimiterGrammar").to_matchable()])
-                                .config(|config| config.min_times(1)),
-                        );
-                    })
-                    .to_matchable(),
+                Delimited::new(vec![
+                    Ref::new("StatementSegment")
+                        .reset_match_budget()
+                        .to_matchable(),
+                ])
+                .config(|this| {
+                    this.allow_trailing();
+                    this.delimiter(
+                        AnyNumberOf::new(vec![Ref::new("DelimiterGrammar").to_matchable()])
+                            .config(|config| config.min_times(1)),
+                    );
+                })
+                .to_matchable(),
             )
             .to_matchable()
             .into(),
@@ -1545,10 +1647,25 @@ pub fn raw_dialect() -> Dialect {
             NodeMatcher::new(
                 SyntaxKind::AlterSequenceOptionsSegment,
                 one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("AS"),
+                        Ref::new("DatatypeSegment")
+                    ]),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("INCREMENT"),
                         Ref::keyword("BY"),
-                        Ref::new("NumericLiteralSegment")
+                        one_of(vec_of_erased![
+                            Ref::new("NumericLiteralSegment"),
+                            Ref::new("QualifiedNumericLiteralSegment")
+                        ])
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("RESTART"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("WITH"),
+                            Ref::new("NumericLiteralSegment")
+                        ])
+                        .config(|this| this.optional())
                     ]),
                     one_of(vec_of_erased![
                         Sequence::new(vec_of_erased![
@@ -1569,11 +1686,13 @@ pub fn raw_dialect() -> Dialect {
                             Ref::keyword("CACHE"),
                             Ref::new("NumericLiteralSegment")
                         ]),
-                        Ref::keyword("NOCACHE")
+                        Ref::keyword("NOCACHE"),
+                        Sequence::new(vec_of_erased![Ref::keyword("NO"), Ref::keyword("CACHE")])
                     ]),
                     one_of(vec_of_erased![
                         Ref::keyword("CYCLE"),
-                        Ref::keyword("NOCYCLE")
+                        Ref::keyword("NOCYCLE"),
+                        Sequence::new(vec_of_erased![Ref::keyword("NO"), Ref::keyword("CYCLE")])
                     ]),
                     one_of(vec_of_erased![
                         Ref::keyword("ORDER"),
@@ -1684,6 +1803,8 @@ pub fn raw_dialect() -> Dialect {
                         Ref::new("PrimaryKeyGrammar"),
                         Ref::new("UniqueKeyGrammar"), // UNIQUE
                         Ref::new("AutoIncrementGrammar"),
+                        Ref::new("GeneratedColumnGrammar"),
+                        Ref::new("IdentityGrammar"),
                         Ref::new("ReferenceDefinitionGrammar"), /* REFERENCES reftable [ (
                                                                  * refcolumn) ] */
                         Ref::new("CommentClauseSegment"),
@@ -1854,8 +1975,12 @@ pub fn raw_dialect() -> Dialect {
         (
             "SchemaReferenceSegment".into(),
             NodeMatcher::new(
-                SyntaxKind::TableReference,
-                Ref::new("ObjectReferenceSegment").to_matchable(),
+                SyntaxKind::SchemaReference,
+                ansi_dialect
+                    .grammar("ObjectReferenceSegment")
+                    .match_grammar()
+                    .unwrap()
+                    .clone(),
             )
             .to_matchable()
             .into(),
@@ -1993,7 +2118,7 @@ pub fn raw_dialect() -> Dialect {
         (
             "SequenceReferenceSegment".into(),
             NodeMatcher::new(
-                SyntaxKind::ColumnReference,
+                SyntaxKind::SequenceReference,
                 Delimited::new(vec![Ref::new("SingleIdentifierGrammar").to_matchable()])
                     .config(|this| {
                         this.delimiter(Ref::new("ObjectReferenceDelimiterGrammar"));
@@ -2087,7 +2212,7 @@ pub fn raw_dialect() -> Dialect {
         (
             "IndexReferenceSegment".into(),
             NodeMatcher::new(
-                SyntaxKind::DatabaseReference,
+                SyntaxKind::IndexReference,
                 Delimited::new(vec![Ref::new("SingleIdentifierGrammar").to_matchable()])
                     .config(|this| {
                         this.delimiter(Ref::new("ObjectReferenceDelimiterGrammar"));
@@ -2374,16 +2499,25 @@ pub fn raw_dialect() -> Dialect {
                         Ref::keyword("DELETE").to_matchable(),
                         Sequence::new(vec![
                             Ref::keyword("UPDATE").to_matchable(),
-                            Ref::keyword("OF").to_matchable(),
-                            Delimited::new(vec![Ref::new("ColumnReferenceSegment").to_matchable()])
-                                //.with_terminators(vec!["OR", "ON"])
+                            Sequence::new(vec![
+                                Ref::keyword("OF").to_matchable(),
+                                Delimited::new(vec![
+                                    Ref::new("ColumnReferenceSegment").to_matchable(),
+                                ])
+                                .config(|this| {
+                                    this.terminators =
+                                        vec_of_erased![Ref::keyword("OR"), Ref::keyword("ON")];
+                                })
                                 .to_matchable(),
+                            ])
+                            .config(|this| this.optional())
+                            .to_matchable(),
                         ])
                         .to_matchable(),
                     ])
                     .config(|this| {
                         this.delimiter(Ref::keyword("OR"));
-                        // .with_terminators(vec!["ON"]);
+                        this.terminators = vec_of_erased![Ref::keyword("ON")];
                     })
                     .to_matchable(),
                     Ref::keyword("ON").to_matchable(),
@@ -2391,14 +2525,14 @@ pub fn raw_dialect() -> Dialect {
                     AnyNumberOf::new(vec_of_erased![
                         Sequence::new(vec_of_erased![
                             Ref::keyword("REFERENCING"),
-                            Ref::keyword("OLD"),
-                            Ref::keyword("ROW"),
-                            Ref::keyword("AS"),
-                            Ref::new("ParameterNameSegment"),
-                            Ref::keyword("NEW"),
-                            Ref::keyword("ROW"),
-                            Ref::keyword("AS"),
-                            Ref::new("ParameterNameSegment"),
+                            AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![Ref::keyword("OLD"), Ref::keyword("NEW")]),
+                                one_of(vec_of_erased![Ref::keyword("ROW"), Ref::keyword("TABLE")])
+                                    .config(|this| this.optional()),
+                                Ref::keyword("AS").optional(),
+                                Ref::new("ParameterNameSegment"),
+                            ])])
+                            .config(|this| this.min_times(1)),
                         ]),
                         Sequence::new(vec_of_erased![
                             Ref::keyword("FROM"),
@@ -2437,16 +2571,33 @@ pub fn raw_dialect() -> Dialect {
                         ]),
                     ])
                     .to_matchable(),
-                    Sequence::new(vec![
-                        Ref::keyword("EXECUTE").to_matchable(),
-                        Ref::keyword("PROCEDURE").to_matchable(),
-                        Ref::new("FunctionNameIdentifierSegment").to_matchable(),
-                        Bracketed::new(vec![
-                            Ref::new("FunctionContentsGrammar")
-                                .optional()
-                                .to_matchable(),
-                        ])
-                        .to_matchable(),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("EXECUTE"),
+                            one_of(vec_of_erased![
+                                Ref::keyword("PROCEDURE"),
+                                Ref::keyword("FUNCTION")
+                            ]),
+                            Ref::new("FunctionNameIdentifierSegment"),
+                            Bracketed::new(vec_of_erased![
+                                Ref::new("FunctionContentsGrammar").optional()
+                            ]),
+                        ]),
+                        // Compound trigger body, e.g. `BEGIN ATOMIC ...; ...; END`.
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("BEGIN"),
+                            Ref::keyword("ATOMIC").optional(),
+                            Delimited::new(vec_of_erased![Ref::new("StatementSegment")]).config(
+                                |this| {
+                                    this.delimiter(Ref::new("DelimiterGrammar"));
+                                    this.allow_trailing();
+                                    this.reset_terminators = true;
+                                    this.terminators = vec_of_erased![Ref::keyword("END")];
+                                }
+                            ),
+                            Ref::keyword("END"),
+                        ]),
+                        Ref::new("StatementSegment"),
                     ])
                     .config(|this| this.optional())
                     .to_matchable(),
@@ -2477,7 +2628,12 @@ pub fn raw_dialect() -> Dialect {
                 SyntaxKind::DescribeStatement,
                 Sequence::new(vec_of_erased![
                     Ref::keyword("DESCRIBE"),
-                    Ref::new("NakedIdentifierSegment"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("TABLE"),
+                        Ref::keyword("VIEW"),
+                        Ref::keyword("FORMATTED")
+                    ])
+                    .config(|this| this.optional()),
                     Ref::new("ObjectReferenceSegment")
                 ])
                 .to_matchable(),
@@ -2499,16 +2655,120 @@ pub fn raw_dialect() -> Dialect {
             .into(),
         ),
         (
-            "ExplainStatementSegment".into(),
+            "CallStatementSegment".into(),
             NodeMatcher::new(
-                SyntaxKind::ExplainStatement,
+                SyntaxKind::CallStatement,
                 Sequence::new(vec_of_erased![
-                    Ref::keyword("EXPLAIN"),
+                    Ref::keyword("CALL"),
+                    Ref::new("FunctionSegment")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "PrepareStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::PrepareStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PREPARE"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                        "DatatypeSegment"
+                    )])])
+                    .config(|this| this.optional()),
+                    one_of(vec_of_erased![Ref::keyword("AS"), Ref::keyword("FROM")]),
                     one_of(vec_of_erased![
                         Ref::new("SelectableGrammar"),
                         Ref::new("InsertStatementSegment"),
                         Ref::new("UpdateStatementSegment"),
-                        Ref::new("DeleteStatementSegment")
+                        Ref::new("DeleteStatementSegment"),
+                        Ref::new("MergeStatementSegment")
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "ExecuteStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ExecuteStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("EXECUTE"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                        "ExpressionSegment"
+                    )])])
+                    .config(|this| this.optional()),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("USING"),
+                        Delimited::new(vec_of_erased![Ref::new("ExpressionSegment")])
+                    ])
+                    .config(|this| this.optional())
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "DeallocateStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::DeallocateStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DEALLOCATE"),
+                    Ref::keyword("PREPARE").optional(),
+                    one_of(vec_of_erased![
+                        Ref::keyword("ALL"),
+                        Ref::new("ObjectReferenceSegment")
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "ExplainStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ExplainStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("EXPLAIN"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("ANALYZE").optional(),
+                            Ref::keyword("VERBOSE").optional()
+                        ]),
+                        Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                            "ExplainOptionSegment"
+                        )])])
+                    ])
+                    .config(|this| this.optional()),
+                    explainable_stmt()
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "ExplainOptionSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ExplainOption,
+                one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        one_of(vec_of_erased![
+                            Ref::keyword("ANALYZE"),
+                            Ref::keyword("VERBOSE")
+                        ]),
+                        Ref::new("BooleanLiteralGrammar").optional()
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FORMAT"),
+                        Ref::new("NakedIdentifierSegment")
                     ])
                 ])
                 .to_matchable(),
@@ -2537,10 +2797,17 @@ pub fn raw_dialect() -> Dialect {
             NodeMatcher::new(
                 SyntaxKind::CreateSequenceOptionsSegment,
                 one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("AS"),
+                        Ref::new("DatatypeSegment")
+                    ]),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("INCREMENT"),
                         Ref::keyword("BY"),
-                        Ref::new("NumericLiteralSegment")
+                        one_of(vec_of_erased![
+                            Ref::new("NumericLiteralSegment"),
+                            Ref::new("QualifiedNumericLiteralSegment")
+                        ])
                     ]),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("START"),
@@ -2566,11 +2833,13 @@ pub fn raw_dialect() -> Dialect {
                             Ref::keyword("CACHE"),
                             Ref::new("NumericLiteralSegment")
                         ]),
-                        Ref::keyword("NOCACHE")
+                        Ref::keyword("NOCACHE"),
+                        Sequence::new(vec_of_erased![Ref::keyword("NO"), Ref::keyword("CACHE")])
                     ]),
                     one_of(vec_of_erased![
                         Ref::keyword("CYCLE"),
-                        Ref::keyword("NOCYCLE")
+                        Ref::keyword("NOCYCLE"),
+                        Sequence::new(vec_of_erased![Ref::keyword("NO"), Ref::keyword("CYCLE")])
                     ]),
                     one_of(vec_of_erased![
                         Ref::keyword("ORDER"),
@@ -2865,6 +3134,51 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // A `SET` statement for session configuration,
+            // e.g. `SET search_path = 'x'` or `SET TIME ZONE 'UTC'`.
+            "SetStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::SetStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("SET"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("SESSION"),
+                        Ref::keyword("LOCAL")
+                    ])
+                    .config(|this| this.optional()),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("TIME"),
+                            Ref::keyword("ZONE"),
+                            one_of(vec_of_erased![
+                                Ref::new("QuotedLiteralSegment"),
+                                Ref::new("IntervalExpressionSegment"),
+                                Ref::keyword("LOCAL"),
+                                Ref::keyword("DEFAULT")
+                            ])
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::new("ParameterNameSegment"),
+                            one_of(vec_of_erased![
+                                Ref::keyword("TO"),
+                                Ref::new("EqualsSegment")
+                            ]),
+                            one_of(vec_of_erased![
+                                Ref::keyword("DEFAULT"),
+                                Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                                    Ref::new("LiteralGrammar"),
+                                    Ref::new("NakedIdentifierSegment")
+                                ])])
+                            ])
+                        ])
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "DropSchemaStatementSegment".into(),
             NodeMatcher::new(
@@ -3004,7 +3318,8 @@ pub fn raw_dialect() -> Dialect {
                         // Create AS syntax:
                         Sequence::new(vec_of_erased![
                             Ref::keyword("AS"),
-                            optionally_bracketed(vec_of_erased![Ref::new("SelectableGrammar")])
+                            optionally_bracketed(vec_of_erased![Ref::new("SelectableGrammar")]),
+                            Ref::new("WithDataClauseSegment").optional()
                         ]),
                         // Create LIKE syntax
                         Sequence::new(vec_of_erased![
@@ -3467,6 +3782,14 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
+        (
+            // Hookpoint for dialects which support `SHOW` statements
+            // e.g. `SHOW TABLES` in MySQL, Snowflake and Spark
+            "ShowStatementSegment".into(),
+            NodeMatcher::new(SyntaxKind::ShowStatement, Nothing::new().to_matchable())
+                .to_matchable()
+                .into(),
+        ),
         (
             "MergeStatementSegment".into(),
             NodeMatcher::new(
@@ -3726,6 +4049,9 @@ pub fn raw_dialect() -> Dialect {
                     Ref::keyword("WHEN"),
                     Sequence::new(vec_of_erased![
                         MetaSegment::implicit_indent(),
+                        // A simple CASE may compare its operand with a leading
+                        // operator, e.g. `CASE a WHEN > 0 THEN ...`.
+                        Ref::new("ComparisonOperatorGrammar").optional(),
                         Ref::new("ExpressionSegment"),
                         MetaSegment::dedent(),
                     ]),
@@ -4014,17 +4340,21 @@ pub fn raw_dialect() -> Dialect {
             "ArrayAccessorSegment".into(),
             NodeMatcher::new(
                 SyntaxKind::ArrayAccessor,
-                Bracketed::new(vec![
-                    Delimited::new(vec![
-                        one_of(vec![
-                            Ref::new("NumericLiteralSegment").to_matchable(),
-                            Ref::new("ExpressionSegment").to_matchable(),
+                Bracketed::new(vec_of_erased![one_of(vec_of_erased![
+                    // Slice access with optional bounds and step: [n:m], [:m], [n:], [n:m:s]
+                    Sequence::new(vec_of_erased![
+                        Ref::new("ArrayAccessorIndexGrammar").optional(),
+                        Ref::new("SliceSegment"),
+                        Ref::new("ArrayAccessorIndexGrammar").optional(),
+                        Sequence::new(vec_of_erased![
+                            Ref::new("SliceSegment"),
+                            Ref::new("ArrayAccessorIndexGrammar").optional()
                         ])
-                        .to_matchable(),
-                    ])
-                    .config(|this| this.delimiter(Ref::new("SliceSegment")))
-                    .to_matchable(),
-                ])
+                        .config(|this| this.optional())
+                    ]),
+                    // Single element access: [n], [OFFSET(n)]
+                    Ref::new("ArrayAccessorIndexGrammar")
+                ])])
                 .config(|this| {
                     this.bracket_type("square");
                     this.parse_mode(ParseMode::Greedy);
@@ -4107,6 +4437,7 @@ pub fn raw_dialect() -> Dialect {
                 SyntaxKind::IntervalExpression,
                 Sequence::new(vec![
                     Ref::keyword("INTERVAL").to_matchable(),
+                    Ref::new("SignedSegmentGrammar").optional().to_matchable(),
                     one_of(vec![
                         Sequence::new(vec![
                             Ref::new("NumericLiteralSegment").to_matchable(),
@@ -4117,7 +4448,13 @@ pub fn raw_dialect() -> Dialect {
                             .to_matchable(),
                         ])
                         .to_matchable(),
-                        Ref::new("QuotedLiteralSegment").to_matchable(),
+                        Sequence::new(vec![
+                            Ref::new("QuotedLiteralSegment").to_matchable(),
+                            Ref::new("IntervalQualifierGrammar")
+                                .optional()
+                                .to_matchable(),
+                        ])
+                        .to_matchable(),
                     ])
                     .to_matchable(),
                 ])
@@ -4126,6 +4463,26 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // e.g. `DAY`, `DAY(3)`, `YEAR TO MONTH` or `SECOND(2, 3)`
+            "IntervalQualifierGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Ref::new("DatetimeUnitSegment"),
+                Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                    "NumericLiteralSegment"
+                )])])
+                .config(|this| this.optional()),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("TO"),
+                    Ref::new("DatetimeUnitSegment"),
+                    Bracketed::new(vec_of_erased![Ref::new("NumericLiteralSegment")])
+                        .config(|this| this.optional())
+                ])
+                .config(|this| this.optional())
+            ])
+            .to_matchable()
+            .into(),
+        ),
         (
             "ArrayTypeSegment".into(),
             NodeMatcher::new(SyntaxKind::ArrayType, Nothing::new().to_matchable())
@@ -4255,6 +4612,14 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // Hookpoint for dialects which support `SELECT ... INTO new_table FROM ...`
+            // e.g. Postgres and T-SQL
+            "IntoClauseSegment".into(),
+            NodeMatcher::new(SyntaxKind::IntoClause, Nothing::new().to_matchable())
+                .to_matchable()
+                .into(),
+        ),
         (
             "FromClauseSegment".into(),
             NodeMatcher::new(
@@ -4555,12 +4920,32 @@ pub fn raw_dialect() -> Dialect {
         (
             "PostFunctionGrammar".into(),
             one_of(vec![
+                // Ordered-set aggregates, e.g. `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`.
+                Sequence::new(vec![
+                    Ref::new("WithinGroupClauseSegment").to_matchable(),
+                    Ref::new("OverClauseSegment").optional().to_matchable(),
+                ])
+                .to_matchable(),
                 Ref::new("OverClauseSegment").to_matchable(),
                 Ref::new("FilterClauseGrammar").to_matchable(),
             ])
             .to_matchable()
             .into(),
         ),
+        (
+            "WithinGroupClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::WithingroupClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITHIN"),
+                    Ref::keyword("GROUP"),
+                    Bracketed::new(vec_of_erased![Ref::new("OrderByClauseSegment").optional()]),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
     ]);
 
     // Assuming `ansi_dialect` is an instance of a struct representing a SQL dialect
@@ -4870,6 +5255,19 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "ArrayAccessorIndexGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::new("NumericLiteralSegment"),
+                Ref::new("ExpressionSegment")
+            ])
+            .config(|this| {
+                // Keep `[:n]` a slice rather than an index holding the bind parameter `:n`.
+                this.exclude = Some(Ref::new("SliceSegment").to_matchable());
+            })
+            .to_matchable()
+            .into(),
+        ),
         (
             "AccessorGrammar".into(),
             AnyNumberOf::new(vec![Ref::new("ArrayAccessorSegment").to_matchable()])
@@ -4963,19 +5361,7 @@ fn lexer_matchers() -> Vec<Matcher> {
     vec![
         Matcher::regex("whitespace", r"[^\S\r\n]+", SyntaxKind::Whitespace),
         Matcher::regex("inline_comment", r"(--|#)[^\n]*", SyntaxKind::InlineComment),
-        Matcher::native("block_comment", block_comment, SyntaxKind::BlockComment)
-            .subdivider(Pattern::legacy(
-                "newline",
-                |_| true,
-                r"\r\n|\n",
-                SyntaxKind::Newline,
-            ))
-            .post_subdivide(Pattern::legacy(
-                "whitespace",
-                |_| true,
-                r"[^\S\r\n]+",
-                SyntaxKind::Whitespace,
-            )),
+        block_comment_matcher(true),
         Matcher::regex(
             "single_quote",
             r"'([^'\\]|\\.|'')*'",
@@ -4993,6 +5379,31 @@ fn lexer_matchers() -> Vec<Matcher> {
             r"\$(\w*)\$[\s\S]*?\$\1\$",
             SyntaxKind::DollarQuote,
         ),
+        Matcher::regex(
+            "escaped_single_quote",
+            r"[eE]'([^'\\]|\\.|'')*'",
+            SyntaxKind::EscapedSingleQuote,
+        ),
+        Matcher::regex(
+            "unicode_single_quote",
+            r"[uU]&'([^']|'')*'",
+            SyntaxKind::UnicodeSingleQuote,
+        ),
+        Matcher::regex(
+            "bit_string_literal",
+            r"[bB]'[01]*'",
+            SyntaxKind::BitStringLiteral,
+        ),
+        Matcher::regex(
+            "hex_literal",
+            r"[xX]'[0-9a-fA-F]*'|0[xX][0-9a-fA-F]+",
+            SyntaxKind::HexLiteral,
+        ),
+        Matcher::regex(
+            "raw_single_quote",
+            r"[rR]'[^']*'",
+            SyntaxKind::RawSingleQuote,
+        ),
         Matcher::native(
             "numeric_literal",
             numeric_literal,
@@ -5061,6 +5472,7 @@ pub fn get_unordered_select_statement_segment_grammar() -> Matchable {
     Sequence::new(vec_of_erased![
         Ref::new("SelectClauseSegment"),
         MetaSegment::dedent(),
+        Ref::new("IntoClauseSegment").optional(),
         Ref::new("FromClauseSegment").optional(),
         Ref::new("WhereClauseSegment").optional(),
         Ref::new("GroupByClauseSegment").optional(),
@@ -5134,6 +5546,7 @@ pub fn statement_segment() -> Matchable {
         Ref::new("AlterTableStatementSegment").to_matchable(),
         Ref::new("CreateSchemaStatementSegment").to_matchable(),
         Ref::new("SetSchemaStatementSegment").to_matchable(),
+        Ref::new("SetStatementSegment").to_matchable(),
         Ref::new("DropSchemaStatementSegment").to_matchable(),
         Ref::new("DropTypeStatementSegment").to_matchable(),
         Ref::new("CreateDatabaseStatementSegment").to_matchable(),
@@ -5151,6 +5564,11 @@ pub fn statement_segment() -> Matchable {
         Ref::new("DropModelStatementSegment").to_matchable(),
         Ref::new("DescribeStatementSegment").to_matchable(),
         Ref::new("UseStatementSegment").to_matchable(),
+        Ref::new("ShowStatementSegment").to_matchable(),
+        Ref::new("CallStatementSegment").to_matchable(),
+        Ref::new("PrepareStatementSegment").to_matchable(),
+        Ref::new("ExecuteStatementSegment").to_matchable(),
+        Ref::new("DeallocateStatementSegment").to_matchable(),
         Ref::new("ExplainStatementSegment").to_matchable(),
         Ref::new("CreateSequenceStatementSegment").to_matchable(),
         Ref::new("AlterSequenceStatementSegment").to_matchable(),
@@ -5212,7 +5630,41 @@ fn numeric_literal(cursor: &mut Cursor) -> bool {
     }
 }
 
-fn block_comment(cursor: &mut Cursor) -> bool {
+/// Block comment matcher, with or without support for nested `/* ... */` pairs.
+///
+/// Dialects which don't allow nesting (e.g. MySQL) can swap it in with
+/// `patch_lexer_matchers`.
+pub(crate) fn block_comment_matcher(nested: bool) -> Matcher {
+    let scan: fn(&mut Cursor) -> bool = if nested {
+        nested_block_comment
+    } else {
+        flat_block_comment
+    };
+
+    Matcher::native("block_comment", scan, SyntaxKind::BlockComment)
+        .subdivider(Pattern::legacy(
+            "newline",
+            |_| true,
+            r"\r\n|\n",
+            SyntaxKind::Newline,
+        ))
+        .post_subdivide(Pattern::legacy(
+            "whitespace",
+            |_| true,
+            r"[^\S\r\n]+",
+            SyntaxKind::Whitespace,
+        ))
+}
+
+fn nested_block_comment(cursor: &mut Cursor) -> bool {
+    block_comment(cursor, true)
+}
+
+fn flat_block_comment(cursor: &mut Cursor) -> bool {
+    block_comment(cursor, false)
+}
+
+fn block_comment(cursor: &mut Cursor, nested: bool) -> bool {
     if cursor.shift() != '/' {
         return false;
     }
@@ -5226,7 +5678,7 @@ fn block_comment(cursor: &mut Cursor) -> bool {
     loop {
         match cursor.shift() {
             '\0' => return false,
-            '/' if cursor.peek() == '*' => {
+            '/' if nested && cursor.peek() == '*' => {
                 cursor.shift();
                 depth += 1;
             }
@@ -290,6 +290,7 @@ FOR
 FORCE
 FOREIGN
 FORMAT
+FORMATTED
 FORTRAN
 FORWARD
 FOUND
@@ -738,6 +739,7 @@ STDDEV_SAMP
 STDIN
 STDOUT
 STORAGE
+STORED
 STRAIGHT_JOIN
 STREAM
 STREAMS
@@ -858,6 +860,7 @@ VERBOSE
 VERSION
 VIEW
 VIEWS
+VIRTUAL
 VOLATILE
 WAITFOR
 WAREHOUSE
@@ -529,9 +529,6 @@ pub fn dialect() -> Dialect {
             Some(vec_of_erased![
                 Ref::new("MsckRepairTableStatementSegment"),
                 Ref::new("UnloadStatementSegment"),
-                Ref::new("PrepareStatementSegment"),
-                Ref::new("ExecuteStatementSegment"),
-                Ref::new("ShowStatementSegment"),
             ]),
             None,
             None,
@@ -245,6 +245,10 @@ pub fn dialect() -> Dialect {
             "TrimParametersGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "WithinGroupClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "ParameterNameSegment".into(),
             one_of(vec_of_erased![
@@ -311,6 +315,14 @@ pub fn dialect() -> Dialect {
             "ForeignKeyGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "GeneratedColumnGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "IdentityGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
     ]);
 
     // Set Keywords
@@ -635,14 +647,14 @@ pub fn dialect() -> Dialect {
         "FileSegment",
         Sequence::new(vec_of_erased![
             Sequence::new(vec_of_erased![one_of(vec_of_erased![
-                Ref::new("MultiStatementSegment"),
-                Ref::new("StatementSegment")
+                Ref::new("MultiStatementSegment").reset_match_budget(),
+                Ref::new("StatementSegment").reset_match_budget()
             ])]),
             AnyNumberOf::new(vec_of_erased![
                 Ref::new("DelimiterGrammar"),
                 one_of(vec_of_erased![
-                    Ref::new("MultiStatementSegment"),
-                    Ref::new("StatementSegment")
+                    Ref::new("MultiStatementSegment").reset_match_budget(),
+                    Ref::new("StatementSegment").reset_match_budget()
                 ])
             ]),
             Ref::new("DelimiterGrammar").optional()
@@ -655,11 +667,9 @@ pub fn dialect() -> Dialect {
         ansi::statement_segment().copy(
             Some(vec_of_erased![
                 Ref::new("DeclareStatementSegment"),
-                Ref::new("SetStatementSegment"),
                 Ref::new("ExportStatementSegment"),
                 Ref::new("CreateExternalTableStatementSegment"),
                 Ref::new("AssertStatementSegment"),
-                Ref::new("CallStatementSegment"),
                 Ref::new("ReturnStatementSegment"),
                 Ref::new("BreakStatementSegment"),
                 Ref::new("LeaveStatementSegment"),
@@ -672,7 +682,11 @@ pub fn dialect() -> Dialect {
             ]),
             None,
             None,
-            None,
+            Some(vec_of_erased![
+                Ref::new("PrepareStatementSegment"),
+                Ref::new("ExecuteStatementSegment"),
+                Ref::new("DeallocateStatementSegment"),
+            ]),
             Vec::new(),
             false,
         ),
@@ -100,6 +100,7 @@ ADD
 ADMIN
 AFTER
 ALTER
+ANALYZE
 APPLY
 ASSERT
 AUTO_INCREMENT
@@ -152,6 +153,7 @@ FILTER
 FIRST
 FOREIGN
 FORMAT
+FORMATTED
 FRIDAY
 FUNCTION
 FUTURE
@@ -277,6 +279,7 @@ USER
 VALUE
 VALUES
 VARYING
+VERBOSE
 VERSION
 VIEW
 WAREHOUSE
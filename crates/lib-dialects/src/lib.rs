@@ -1,5 +1,10 @@
+use std::sync::{LazyLock, Mutex};
+
+use ahash::AHashMap;
 use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::parser::lexer::Matcher;
 
 pub mod ansi;
 mod ansi_keywords;
@@ -50,7 +55,25 @@ pub mod trino;
 #[cfg(feature = "trino")]
 mod trino_keywords;
 
+/// Returns the expanded dialect for `kind`, or `None` if support for it wasn't
+/// compiled in.
+///
+/// Expanding a dialect builds its whole grammar, so each one is only built once
+/// per process. Later calls clone the cached dialect, which shares its
+/// matchables rather than rebuilding them.
 pub fn kind_to_dialect(kind: &DialectKind) -> Option<Dialect> {
+    static DIALECTS: LazyLock<Mutex<AHashMap<DialectKind, Option<Dialect>>>> =
+        LazyLock::new(Default::default);
+
+    DIALECTS
+        .lock()
+        .unwrap()
+        .entry(*kind)
+        .or_insert_with(|| build_dialect(kind))
+        .clone()
+}
+
+fn build_dialect(kind: &DialectKind) -> Option<Dialect> {
     #[allow(unreachable_patterns)]
     Some(match kind {
         DialectKind::Ansi => ansi::dialect(),
@@ -81,3 +104,22 @@ pub fn kind_to_dialect(kind: &DialectKind) -> Option<Dialect> {
         _ => return None,
     })
 }
+
+/// Returns the lexer matcher for one of the bind parameter styles which can be
+/// enabled with the `bind_parameter_styles` config option, or `None` if `style`
+/// isn't known.
+///
+/// The styles are `colon` (`:name`), `numeric_colon` (`:1`), `dollar` (`$name`
+/// or `$1`), `at` (`@name`) and `pyformat` (`%(name)s`).
+pub fn bind_parameter_matcher(style: &str) -> Option<Matcher> {
+    let (name, pattern) = match style {
+        "colon" => ("colon_parameter", r":[a-zA-Z_]\w*"),
+        "numeric_colon" => ("numeric_colon_parameter", r":\d+"),
+        "dollar" => ("dollar_parameter", r"\$\w+"),
+        "at" => ("at_parameter", r"@[a-zA-Z_]\w*"),
+        "pyformat" => ("pyformat_parameter", r"%\([a-zA-Z_]\w*\)s"),
+        _ => return None,
+    };
+
+    Some(Matcher::regex(name, pattern, SyntaxKind::BindParameter))
+}
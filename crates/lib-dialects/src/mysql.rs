@@ -1,8 +1,15 @@
 use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::init::DialectKind;
 use sqruff_lib_core::dialects::syntax::SyntaxKind;
-use sqruff_lib_core::helpers::Config;
+use sqruff_lib_core::helpers::{Config, ToMatchable};
+use sqruff_lib_core::parser::grammar::anyof::one_of;
+use sqruff_lib_core::parser::grammar::base::Ref;
+use sqruff_lib_core::parser::grammar::delimited::Delimited;
+use sqruff_lib_core::parser::grammar::sequence::Sequence;
 use sqruff_lib_core::parser::lexer::Matcher;
+use sqruff_lib_core::parser::matchable::MatchableTrait;
+use sqruff_lib_core::parser::node_matcher::NodeMatcher;
+use sqruff_lib_core::vec_of_erased;
 
 use super::ansi;
 
@@ -14,11 +21,242 @@ pub fn raw_dialect() -> Dialect {
     let mut mysql = ansi::raw_dialect();
     mysql.name = DialectKind::Mysql;
 
-    mysql.patch_lexer_matchers(vec![Matcher::regex(
-        "inline_comment",
-        r"(^--|-- |#)[^\n]*",
-        SyntaxKind::InlineComment,
-    )]);
+    mysql.patch_lexer_matchers(vec![
+        Matcher::regex(
+            "inline_comment",
+            r"(^--|-- |#)[^\n]*",
+            SyntaxKind::InlineComment,
+        ),
+        // MySQL doesn't support nested block comments, the first `*/` closes the comment.
+        ansi::block_comment_matcher(false),
+    ]);
+
+    // User-defined and system variables, e.g. `@my_var` or `@@session.sql_mode`.
+    mysql.insert_lexer_matchers(
+        vec![Matcher::regex(
+            "at_sign_parameter",
+            r"@@?[a-zA-Z_][\w.$]*",
+            SyntaxKind::BindParameter,
+        )],
+        "equals",
+    );
+
+    mysql
+        .sets_mut("unreserved_keywords")
+        .extend(["DUMPFILE", "STATUS"]);
+
+    mysql.add([
+        (
+            // Optional filter shared by most `SHOW` statements.
+            "LikeOrWhereGrammar".into(),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("LIKE"),
+                    Ref::new("QuotedLiteralSegment")
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WHERE"),
+                    Ref::new("ExpressionSegment")
+                ])
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // https://dev.mysql.com/doc/refman/8.0/en/select-into.html
+            "IntoClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::IntoClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("INTO"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("OUTFILE"),
+                            Ref::new("QuotedLiteralSegment"),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("CHARACTER"),
+                                Ref::keyword("SET"),
+                                Ref::new("NakedIdentifierSegment")
+                            ])
+                            .config(|this| this.optional()),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![
+                                    Ref::keyword("FIELDS"),
+                                    Ref::keyword("COLUMNS")
+                                ]),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("TERMINATED"),
+                                    Ref::keyword("BY"),
+                                    Ref::new("QuotedLiteralSegment")
+                                ])
+                                .config(|this| this.optional()),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("OPTIONALLY").optional(),
+                                    Ref::keyword("ENCLOSED"),
+                                    Ref::keyword("BY"),
+                                    Ref::new("QuotedLiteralSegment")
+                                ])
+                                .config(|this| this.optional()),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("ESCAPED"),
+                                    Ref::keyword("BY"),
+                                    Ref::new("QuotedLiteralSegment")
+                                ])
+                                .config(|this| this.optional())
+                            ])
+                            .config(|this| this.optional()),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("LINES"),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("STARTING"),
+                                    Ref::keyword("BY"),
+                                    Ref::new("QuotedLiteralSegment")
+                                ])
+                                .config(|this| this.optional()),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("TERMINATED"),
+                                    Ref::keyword("BY"),
+                                    Ref::new("QuotedLiteralSegment")
+                                ])
+                                .config(|this| this.optional())
+                            ])
+                            .config(|this| this.optional())
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("DUMPFILE"),
+                            Ref::new("QuotedLiteralSegment")
+                        ]),
+                        // User-defined variables, or local variables inside stored programs.
+                        Delimited::new(vec_of_erased![
+                            Ref::new("BindParameterSegment"),
+                            Ref::new("NakedIdentifierSegment")
+                        ])
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // https://dev.mysql.com/doc/refman/8.0/en/show.html
+            "ShowStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ShowStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("SHOW"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("FULL").optional(),
+                            Ref::keyword("TABLES"),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")]),
+                                Ref::new("DatabaseReferenceSegment")
+                            ])
+                            .config(|this| this.optional()),
+                            Ref::new("LikeOrWhereGrammar").optional()
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("FULL").optional(),
+                            one_of(vec_of_erased![
+                                Ref::keyword("COLUMNS"),
+                                Ref::keyword("FIELDS")
+                            ]),
+                            one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")]),
+                            Ref::new("TableReferenceSegment"),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")]),
+                                Ref::new("DatabaseReferenceSegment")
+                            ])
+                            .config(|this| this.optional()),
+                            Ref::new("LikeOrWhereGrammar").optional()
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("CREATE"),
+                            one_of(vec_of_erased![
+                                Sequence::new(vec_of_erased![
+                                    one_of(vec_of_erased![
+                                        Ref::keyword("TABLE"),
+                                        Ref::keyword("VIEW")
+                                    ]),
+                                    Ref::new("TableReferenceSegment")
+                                ]),
+                                Sequence::new(vec_of_erased![
+                                    one_of(vec_of_erased![
+                                        Ref::keyword("DATABASE"),
+                                        Ref::keyword("SCHEMA")
+                                    ]),
+                                    Ref::new("DatabaseReferenceSegment")
+                                ])
+                            ])
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("DATABASES"),
+                                Ref::keyword("SCHEMAS")
+                            ]),
+                            Ref::new("LikeOrWhereGrammar").optional()
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("GLOBAL"),
+                                Ref::keyword("SESSION")
+                            ])
+                            .config(|this| this.optional()),
+                            one_of(vec_of_erased![
+                                Ref::keyword("VARIABLES"),
+                                Ref::keyword("STATUS")
+                            ]),
+                            Ref::new("LikeOrWhereGrammar").optional()
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("FULL").optional(),
+                            Ref::keyword("PROCESSLIST")
+                        ])
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+    ]);
+
+    // `INTO` may also close a `SELECT`, e.g. `SELECT a FROM t WHERE b INTO @c`.
+    for terminators in [
+        "SelectClauseTerminatorGrammar",
+        "FromClauseTerminatorGrammar",
+        "WhereClauseTerminatorGrammar",
+        "GroupByClauseTerminatorGrammar",
+        "HavingClauseTerminatorGrammar",
+        "OrderByClauseTerminators",
+    ] {
+        let grammar = mysql.grammar(terminators).copy(
+            Some(vec_of_erased![Ref::keyword("INTO")]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        );
+        mysql.add([(terminators.into(), grammar.into())]);
+    }
+
+    mysql.replace_grammar(
+        "SelectStatementSegment",
+        mysql
+            .grammar("SelectStatementSegment")
+            .match_grammar()
+            .unwrap()
+            .copy(
+                Some(vec_of_erased![Ref::new("IntoClauseSegment").optional()]),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+            ),
+    );
 
     mysql
 }
@@ -35,7 +35,49 @@ pub fn raw_dialect() -> Dialect {
         "equals",
     );
 
-    postgres.insert_lexer_matchers(vec![
+    postgres.insert_lexer_matchers(
+        vec![
+            Matcher::regex(
+                "unicode_double_quote",
+                r#"(?s)U&".+?"(\s*UESCAPE\s*\'[^0-9A-Fa-f\'+\-\s)]\')?"#,
+                SyntaxKind::UnicodeDoubleQuote,
+            ),
+            Matcher::regex(
+                "json_operator",
+                r#"->>|#>>|->|#>|@>|<@|\?\||\?|\?&|#-"#,
+                SyntaxKind::JsonOperator,
+            ),
+            Matcher::string("at", "@", SyntaxKind::At),
+        ],
+        "like_operator",
+    );
+
+    postgres.insert_lexer_matchers(
+        vec![
+            Matcher::legacy(
+                "meta_command",
+                |s| s.starts_with("\\"),
+                r"\\([^\\\r\n])+((\\\\)|(?=\n)|(?=\r\n))?",
+                SyntaxKind::Comment,
+            ),
+            // Positional parameters of prepared statements and functions, e.g. `$1`.
+            Matcher::regex("dollar_parameter", r"\$\d+", SyntaxKind::BindParameter),
+        ],
+        "word",
+    );
+
+    // Python DB-API `pyformat` parameters, as used by psycopg, e.g. `%(name)s`.
+    postgres.insert_lexer_matchers(
+        vec![Matcher::regex(
+            "pyformat_parameter",
+            r"%\([a-zA-Z_][\w]*\)s",
+            SyntaxKind::BindParameter,
+        )],
+        "percent",
+    );
+
+    postgres.patch_lexer_matchers(vec![
+        Matcher::regex("inline_comment", r"(--)[^\n]*", SyntaxKind::InlineComment),
         Matcher::legacy(
             "unicode_single_quote",
             |s| s.starts_with("U&'"),
@@ -48,47 +90,11 @@ pub fn raw_dialect() -> Dialect {
             r"(?s)E(('')+?(?!')|'.*?((?<!\\)(?:\\\\)*(?<!')(?:'')*|(?<!\\)(?:\\\\)*\\(?<!')(?:'')*')'(?!'))",
             SyntaxKind::EscapedSingleQuote
         ),
-        Matcher::regex(
-            "unicode_double_quote",
-            r#"(?s)U&".+?"(\s*UESCAPE\s*\'[^0-9A-Fa-f\'+\-\s)]\')?"#,
-            SyntaxKind::UnicodeDoubleQuote
-        ),
-        Matcher::regex(
-            "json_operator",
-            r#"->>|#>>|->|#>|@>|<@|\?\||\?|\?&|#-"#,
-            SyntaxKind::JsonOperator
-        ),
-        Matcher::string(
-            "at",
-            "@",
-            SyntaxKind::At
-        ),
         Matcher::regex(
             "bit_string_literal",
             r#"[bBxX]'[0-9a-fA-F]*'"#,
             SyntaxKind::BitStringLiteral
         ),
-    ], "like_operator");
-
-    postgres.insert_lexer_matchers(
-        vec![
-            Matcher::legacy(
-                "meta_command",
-                |s| s.starts_with("\\"),
-                r"\\([^\\\r\n])+((\\\\)|(?=\n)|(?=\r\n))?",
-                SyntaxKind::Comment,
-            ),
-            Matcher::regex(
-                "dollar_numeric_literal",
-                r"\$\d+",
-                SyntaxKind::DollarNumericLiteral,
-            ),
-        ],
-        "word",
-    );
-
-    postgres.patch_lexer_matchers(vec![
-        Matcher::regex("inline_comment", r"(--)[^\n]*", SyntaxKind::InlineComment),
         Matcher::legacy(
             "single_quote",
             |s| s.starts_with("'"),
@@ -249,15 +255,6 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
-        (
-            "DollarNumericLiteralSegment".into(),
-            TypedParser::new(
-                SyntaxKind::DollarNumericLiteral,
-                SyntaxKind::DollarNumericLiteral,
-            )
-            .to_matchable()
-            .into(),
-        ),
         (
             "ForeignDataWrapperGrammar".into(),
             Sequence::new(vec_of_erased![
@@ -569,10 +566,7 @@ pub fn raw_dialect() -> Dialect {
                 postgres
                     .grammar("LiteralGrammar")
                     .copy(
-                        Some(vec_of_erased![
-                            Ref::new("DollarNumericLiteralSegment"),
-                            Ref::new("PsqlVariableGrammar")
-                        ]),
+                        Some(vec_of_erased![Ref::new("PsqlVariableGrammar")]),
                         None,
                         Some(Ref::new("ArrayLiteralSegment").to_matchable()),
                         None,
@@ -1703,11 +1697,9 @@ pub fn raw_dialect() -> Dialect {
     postgres.replace_grammar(
         "UnorderedSelectStatementSegment",
         ansi::get_unordered_select_statement_segment_grammar().copy(
-            Some(vec![
-                Ref::new("IntoClauseSegment").optional().to_matchable(),
-            ]),
             None,
-            Some(Ref::new("FromClauseSegment").optional().to_matchable()),
+            None,
+            None,
             None,
             vec![
                 Sequence::new(vec_of_erased![
@@ -1811,58 +1803,39 @@ pub fn raw_dialect() -> Dialect {
         .to_matchable(),
     );
 
-    postgres.add([
-        (
-            "WithinGroupClauseSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::WithingroupClause,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("WITHIN"),
-                    Ref::keyword("GROUP"),
-                    Bracketed::new(vec_of_erased![Ref::new("OrderByClauseSegment").optional()]),
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
-        (
-            "GroupByClauseSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::GroupbyClause,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("GROUP"),
-                    Ref::keyword("BY"),
-                    MetaSegment::indent(),
-                    Delimited::new(vec_of_erased![one_of(vec_of_erased![
-                        Ref::new("ColumnReferenceSegment"),
-                        Ref::new("NumericLiteralSegment"),
-                        Ref::new("CubeRollupClauseSegment"),
-                        Ref::new("GroupingSetsClauseSegment"),
-                        Ref::new("ExpressionSegment"),
-                        Bracketed::new(vec_of_erased![]),
-                    ])])
-                    .config(|this| {
-                        this.terminators = vec_of_erased![
-                            Sequence::new(vec_of_erased![
-                                Ref::keyword("ORDER"),
-                                Ref::keyword("BY")
-                            ]),
-                            Ref::keyword("LIMIT"),
-                            Ref::keyword("HAVING"),
-                            Ref::keyword("QUALIFY"),
-                            Ref::keyword("WINDOW"),
-                            Ref::new("SetOperatorSegment"),
-                        ];
-                    }),
-                    MetaSegment::dedent(),
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
-    ]);
+    postgres.add([(
+        "GroupByClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::GroupbyClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("GROUP"),
+                Ref::keyword("BY"),
+                MetaSegment::indent(),
+                Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                    Ref::new("ColumnReferenceSegment"),
+                    Ref::new("NumericLiteralSegment"),
+                    Ref::new("CubeRollupClauseSegment"),
+                    Ref::new("GroupingSetsClauseSegment"),
+                    Ref::new("ExpressionSegment"),
+                    Bracketed::new(vec_of_erased![]),
+                ])])
+                .config(|this| {
+                    this.terminators = vec_of_erased![
+                        Sequence::new(vec_of_erased![Ref::keyword("ORDER"), Ref::keyword("BY")]),
+                        Ref::keyword("LIMIT"),
+                        Ref::keyword("HAVING"),
+                        Ref::keyword("QUALIFY"),
+                        Ref::keyword("WINDOW"),
+                        Ref::new("SetOperatorSegment"),
+                    ];
+                }),
+                MetaSegment::dedent(),
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
 
     postgres.replace_grammar(
         "CreateRoleStatementSegment",
@@ -5477,19 +5450,6 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
-        (
-            "CallStoredProcedureSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::CallStatement,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("CALL"),
-                    Ref::new("FunctionSegment")
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
         (
             "CreateDomainStatementSegment".into(),
             NodeMatcher::new(
@@ -6761,7 +6721,6 @@ pub fn statement_segment() -> Matchable {
             Ref::new("CreateTableAsStatementSegment"),
             Ref::new("AlterTriggerStatementSegment"),
             Ref::new("AlterAggregateStatementSegment"),
-            Ref::new("SetStatementSegment"),
             Ref::new("AlterPolicyStatementSegment"),
             Ref::new("CreatePolicyStatementSegment"),
             Ref::new("DropPolicyStatementSegment"),
@@ -6803,7 +6762,6 @@ pub fn statement_segment() -> Matchable {
             Ref::new("LockTableStatementSegment"),
             Ref::new("ClusterStatementSegment"),
             Ref::new("CreateCollationStatementSegment"),
-            Ref::new("CallStoredProcedureSegment"),
             Ref::new("CreateServerStatementSegment"),
             Ref::new("CreateUserMappingStatementSegment"),
             Ref::new("ImportForeignSchemaStatementSegment"),
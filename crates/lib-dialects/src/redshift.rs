@@ -2298,7 +2298,6 @@ pub fn raw_dialect() -> Dialect {
                     Ref::new("CloseStatementSegment"),
                     Ref::new("AnalyzeCompressionStatementSegment"),
                     Ref::new("AlterProcedureStatementSegment"),
-                    Ref::new("CallStatementSegment"),
                     Ref::new("CreateRlsPolicyStatementSegment"),
                     Ref::new("ManageRlsPolicyStatementSegment"),
                     Ref::new("DropRlsPolicyStatementSegment"),
@@ -438,6 +438,7 @@ FLOOR
 FOLLOWING
 FORCE
 FORMAT
+FORMATTED
 FORTRAN
 FORWARD
 FOUND
@@ -1288,9 +1288,19 @@ pub fn dialect() -> Dialect {
         .to_matchable(),
     );
 
+    // Snowflake's own `CallStatementSegment` is listed after `CallStoredProcedureSegment`.
+    let ansi_statement_segment = ansi::statement_segment().copy(
+        None,
+        None,
+        None,
+        Some(vec_of_erased![Ref::new("CallStatementSegment")]),
+        Vec::new(),
+        false,
+    );
+
     snowflake_dialect.replace_grammar(
         "StatementSegment",
-        ansi::statement_segment().copy(
+        ansi_statement_segment.copy(
             Some(vec_of_erased![
                 Ref::new("AccessStatementSegment"),
                 Ref::new("CreateStatementSegment"),
@@ -1301,7 +1311,6 @@ pub fn dialect() -> Dialect {
                 Ref::new("ScriptingBlockStatementSegment"),
                 Ref::new("ScriptingLetStatementSegment"),
                 Ref::new("ReturnStatementSegment"),
-                Ref::new("ShowStatementSegment"),
                 Ref::new("AlterUserStatementSegment"),
                 Ref::new("AlterSessionStatementSegment"),
                 Ref::new("AlterTaskStatementSegment"),
@@ -1351,6 +1360,10 @@ pub fn dialect() -> Dialect {
             Some(vec_of_erased![
                 Ref::new("CreateIndexStatementSegment"),
                 Ref::new("DropIndexStatementSegment"),
+                Ref::new("SetStatementSegment"),
+                Ref::new("PrepareStatementSegment"),
+                Ref::new("ExecuteStatementSegment"),
+                Ref::new("DeallocateStatementSegment"),
             ]),
             Vec::new(),
             false,
@@ -5,7 +5,7 @@ use sqruff_lib_core::helpers::{Config, ToMatchable};
 use sqruff_lib_core::parser::grammar::anyof::{
     AnyNumberOf, any_set_of, one_of, optionally_bracketed,
 };
-use sqruff_lib_core::parser::grammar::base::{Anything, Ref};
+use sqruff_lib_core::parser::grammar::base::{Anything, Nothing, Ref};
 use sqruff_lib_core::parser::grammar::conditional::Conditional;
 use sqruff_lib_core::parser::grammar::delimited::Delimited;
 use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
@@ -443,6 +443,11 @@ pub fn raw_dialect() -> Dialect {
     ]);
 
     sparksql_dialect.add([
+        // Generated columns have their own `GeneratedColumnDefinitionSegment`.
+        (
+            "GeneratedColumnGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "FileLiteralSegment".into(),
             TypedParser::new(SyntaxKind::FileLiteral, SyntaxKind::FileLiteral)
@@ -2869,7 +2874,7 @@ pub fn raw_dialect() -> Dialect {
             // https://spark.apache.org/docs/latest/sql-ref-syntax-aux-show-tables.html
             // https://spark.apache.org/docs/latest/sql-ref-syntax-aux-show-tblproperties.html
             // https://spark.apache.org/docs/latest/sql-ref-syntax-aux-show-views.html
-            "ShowStatement".into(),
+            "ShowStatementSegment".into(),
             NodeMatcher::new(
                 SyntaxKind::ShowStatement,
                 Sequence::new(vec_of_erased![
@@ -2917,8 +2922,6 @@ pub fn raw_dialect() -> Dialect {
                 Ref::new("ListJarSegment"),
                 Ref::new("RefreshStatementSegment"),
                 Ref::new("ResetStatementSegment"),
-                Ref::new("SetStatementSegment"),
-                Ref::new("ShowStatement"),
                 Ref::new("UncacheTableSegment"),
                 Ref::new("InsertOverwriteDirectorySegment"),
                 Ref::new("InsertOverwriteDirectoryHiveFmtSegment"),
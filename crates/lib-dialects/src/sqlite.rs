@@ -6,6 +6,7 @@ use sqruff_lib_core::parser::grammar::anyof::{AnyNumberOf, one_of, optionally_br
 use sqruff_lib_core::parser::grammar::base::{Anything, Nothing, Ref};
 use sqruff_lib_core::parser::grammar::delimited::Delimited;
 use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
+use sqruff_lib_core::parser::lexer::Matcher;
 use sqruff_lib_core::parser::matchable::MatchableTrait;
 use sqruff_lib_core::parser::node_matcher::NodeMatcher;
 use sqruff_lib_core::parser::parsers::TypedParser;
@@ -33,11 +34,48 @@ pub fn raw_dialect() -> Dialect {
         .sets_mut("unreserved_keywords")
         .extend(UNRESERVED_KEYWORDS);
 
+    // https://www.sqlite.org/lang_expr.html#parameters
+    sqlite_dialect.insert_lexer_matchers(
+        vec![Matcher::regex(
+            "bind_parameter",
+            r"[:@$][a-zA-Z_][\w]*",
+            SyntaxKind::BindParameter,
+        )],
+        "colon",
+    );
+
     sqlite_dialect.add([
         (
             "ColumnConstraintDefaultGrammar".into(),
             Ref::new("ExpressionSegment").to_matchable().into(),
         ),
+        (
+            "GeneratedColumnGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("GENERATED"),
+                    Ref::keyword("ALWAYS")
+                ])
+                .config(|config| {
+                    config.optional();
+                }),
+                Ref::keyword("AS"),
+                Bracketed::new(vec_of_erased![Ref::new("ExpressionSegment")]),
+                one_of(vec_of_erased![
+                    Ref::keyword("STORED"),
+                    Ref::keyword("VIRTUAL")
+                ])
+                .config(|config| {
+                    config.optional();
+                })
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "IdentityGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "BooleanBinaryOperatorGrammar".into(),
             one_of(vec_of_erased![
@@ -108,6 +146,10 @@ pub fn raw_dialect() -> Dialect {
             "TimeZoneGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "WithinGroupClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "FetchClauseSegment".into(),
             Nothing::new().to_matchable().into(),
@@ -569,6 +611,21 @@ pub fn raw_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    // https://www.sqlite.org/lang_explain.html
+    sqlite_dialect.replace_grammar(
+        "ExplainStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("EXPLAIN"),
+            Sequence::new(vec_of_erased![Ref::keyword("QUERY"), Ref::keyword("PLAN")]).config(
+                |config| {
+                    config.optional();
+                }
+            ),
+            super::ansi::explainable_stmt()
+        ])
+        .to_matchable(),
+    );
+
     sqlite_dialect.add([(
         "PragmaReferenceSegment".into(),
         NodeMatcher::new(
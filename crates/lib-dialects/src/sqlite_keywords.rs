@@ -197,6 +197,7 @@ pub(crate) const UNRESERVED_KEYWORDS: &[&str] = &[
     "PASSIVE",
     "RESTART",
     "RESET",
+    "STORED",
     "STRICT",
     "BINARY",
     "NOCASE",
@@ -45,6 +45,11 @@ pub fn dialect() -> Dialect {
     );
 
     trino_dialect.add([
+        (
+            // Trino has no temporary or transient tables.
+            "TemporaryTransientGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "RightArrowOperator".into(),
             StringParser::new("->", SyntaxKind::BinaryOperator)
@@ -592,20 +597,6 @@ pub fn dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
-        (
-            "PostFunctionGrammar".into(),
-            super::ansi::raw_dialect()
-                .grammar("PostFunctionGrammar")
-                .copy(
-                    Some(vec_of_erased![Ref::new("WithinGroupClauseSegment")]),
-                    None,
-                    None,
-                    Some(vec_of_erased![Ref::new("TransactionStatementSegment")]),
-                    Vec::new(),
-                    false,
-                )
-                .into(),
-        ),
         (
             // ON OVERFLOW clause of listagg function.
             // https://trino.io/docs/current/functions/aggregate.html#array_agg
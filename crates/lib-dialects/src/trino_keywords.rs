@@ -134,6 +134,7 @@ FINAL
 FIRST
 FOLLOWING
 FORMAT
+FORMATTED
 FUNCTIONS
 GRACE
 GRANT
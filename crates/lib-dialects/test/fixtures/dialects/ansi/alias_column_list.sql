@@ -0,0 +1,7 @@
+select v.a, v.b from (values (1, 2), (3, 4)) as v (a, b);
+
+select v.a from (values (1), (2)) v (a);
+
+select f.x from my_func(1) as "f" ("x");
+
+select a as 'quoted_alias' from t;
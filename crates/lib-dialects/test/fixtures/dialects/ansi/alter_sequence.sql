@@ -7,3 +7,9 @@ ALTER SEQUENCE foo NOCACHE CYCLE;
 ALTER SEQUENCE foo NOORDER CACHE 5 NOCYCLE;
 
 ALTER SEQUENCE foo ORDER;
+
+ALTER SEQUENCE foo RESTART;
+
+ALTER SEQUENCE foo RESTART WITH 10 NO CYCLE;
+
+ALTER SEQUENCE foo AS INTEGER INCREMENT BY -5 NO CACHE;
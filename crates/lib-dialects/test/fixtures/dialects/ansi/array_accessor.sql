@@ -0,0 +1,15 @@
+select
+    my_array[1],
+    my_array[1][2],
+    my_array[1:3],
+    my_array[1:10:2],
+    my_array[:3],
+    my_array[2:],
+    my_array[:],
+    my_array[i:j],
+    my_array[1:n],
+    my_array[:n],
+    my_array[OFFSET(1)],
+    my_array[SAFE_OFFSET(1)],
+    my_array[ORDINAL(1)]
+from t;
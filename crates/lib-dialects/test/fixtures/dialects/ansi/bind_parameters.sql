@@ -0,0 +1,8 @@
+SELECT *
+FROM users
+WHERE id = :user_id AND created_at > :since;
+
+SELECT CAST(:amount AS DECIMAL), price::INT
+FROM orders;
+
+UPDATE users SET name = :name WHERE id = :id;
@@ -0,0 +1,5 @@
+CALL my_procedure();
+
+CALL my_schema.my_procedure(1, 'two', other_col);
+
+CALL my_db.my_schema.refresh_stats(CURRENT_DATE);
@@ -13,3 +13,7 @@ CREATE SEQUENCE foo NOCACHE;
 CREATE SEQUENCE foo NOCYCLE ORDER;
 
 CREATE SEQUENCE foo NOORDER;
+
+CREATE SEQUENCE foo AS BIGINT START WITH 1 INCREMENT BY -1 NO CYCLE;
+
+CREATE SEQUENCE foo NO CACHE;
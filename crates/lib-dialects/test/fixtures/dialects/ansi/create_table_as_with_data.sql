@@ -0,0 +1,5 @@
+create table t2 as select a from t with data;
+
+create table t3 as (select a from t) with no data;
+
+create view v as select a from t with no schema binding;
@@ -0,0 +1,12 @@
+CREATE TABLE orders (
+    id INT GENERATED ALWAYS AS IDENTITY,
+    price DECIMAL(10, 2),
+    quantity INT,
+    total DECIMAL(10, 2) GENERATED ALWAYS AS (price * quantity) STORED,
+    discounted DECIMAL(10, 2) GENERATED ALWAYS AS (price * 0.9) VIRTUAL
+);
+
+CREATE TABLE events (
+    event_id BIGINT GENERATED BY DEFAULT AS IDENTITY (START WITH 100 INCREMENT BY 10),
+    name VARCHAR(100) NOT NULL
+);
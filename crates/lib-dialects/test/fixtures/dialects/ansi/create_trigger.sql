@@ -7,3 +7,13 @@ CREATE TRIGGER foo AFTER UPDATE OF bar, baz ON bar EXECUTE PROCEDURE proc(args);
 CREATE TRIGGER foo INSTEAD OF DELETE ON bar FROM baz DEFERRABLE INITIALLY IMMEDIATE FOR EACH ROW EXECUTE PROCEDURE proc(args);
 
 CREATE TRIGGER foo BEFORE INSERT ON bar WHEN (a=b) EXECUTE PROCEDURE proc(args);
+
+CREATE TRIGGER foo AFTER UPDATE ON bar REFERENCING OLD ROW AS o NEW ROW AS n FOR EACH ROW EXECUTE FUNCTION proc();
+
+CREATE TRIGGER foo AFTER INSERT ON bar REFERENCING NEW TABLE AS inserted FOR EACH STATEMENT INSERT INTO audit SELECT * FROM inserted;
+
+CREATE TRIGGER foo AFTER DELETE ON bar REFERENCING OLD o FOR EACH ROW WHEN (o.id > 0)
+BEGIN ATOMIC
+    DELETE FROM baz WHERE baz.id = o.id;
+    INSERT INTO audit VALUES (o.id);
+END;
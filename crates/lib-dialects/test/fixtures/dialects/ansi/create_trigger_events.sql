@@ -0,0 +1,7 @@
+CREATE TRIGGER foo AFTER INSERT OR DELETE ON bar EXECUTE PROCEDURE proc(args);
+
+CREATE TRIGGER foo AFTER UPDATE OF a, b OR INSERT ON bar EXECUTE PROCEDURE proc(args);
+
+CREATE TRIGGER foo BEFORE INSERT OR UPDATE OF a OR DELETE ON bar EXECUTE PROCEDURE proc(args);
+
+CREATE TRIGGER foo BEFORE DELETE OR UPDATE OF a, b, c ON bar FOR EACH ROW EXECUTE PROCEDURE proc(args);
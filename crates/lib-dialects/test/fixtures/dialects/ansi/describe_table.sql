@@ -1 +1,7 @@
 describe table "my_table";
+
+describe my_schema.my_table;
+
+describe view my_view;
+
+describe formatted my_table;
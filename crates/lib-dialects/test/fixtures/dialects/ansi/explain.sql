@@ -0,0 +1,11 @@
+explain select a from b;
+
+explain analyze select a from b;
+
+explain analyze verbose select a from b;
+
+explain verbose update b set a = 1;
+
+explain (analyze, verbose) select a from b;
+
+explain (analyze true, format json) delete from b where a = 1;
@@ -0,0 +1,15 @@
+select interval '1-2' year to month;
+
+select interval '10' day(3);
+
+select interval '3 04:05:06' day to second(3);
+
+select interval '12:30' hour to minute;
+
+select interval -'1' day;
+
+select interval + '2' hour;
+
+select interval '7' day;
+
+select interval 7 day;
@@ -0,0 +1,3 @@
+-- Block comments can be nested.
+/* outer /* inner */ still a comment */
+SELECT 1 /* a /* b /* c */ */ */ AS x;
@@ -0,0 +1,17 @@
+PREPARE get_user (INT) AS
+SELECT * FROM users WHERE active = TRUE;
+
+PREPARE add_user FROM
+INSERT INTO users (name) VALUES ('alice');
+
+EXECUTE get_user (42);
+
+EXECUTE add_user;
+
+EXECUTE get_user USING 42;
+
+DEALLOCATE get_user;
+
+DEALLOCATE PREPARE add_user;
+
+DEALLOCATE ALL;
@@ -0,0 +1,15 @@
+select
+    case status
+        when 1 then 'active'
+        when 2 then 'inactive'
+        else 'unknown'
+    end as status_name
+from accounts;
+
+select
+    case balance
+        when < 0 then 'negative'
+        when = 0 then 'zero'
+        else 'positive'
+    end as balance_sign
+from accounts;
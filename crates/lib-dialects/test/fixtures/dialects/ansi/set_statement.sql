@@ -0,0 +1,13 @@
+SET search_path = 'my_schema';
+
+SET SESSION statement_timeout TO 5000;
+
+SET LOCAL my_setting TO DEFAULT;
+
+SET datestyle = iso, mdy;
+
+SET TIME ZONE 'UTC';
+
+SET SESSION TIME ZONE LOCAL;
+
+SET TIME ZONE INTERVAL '-08:00';
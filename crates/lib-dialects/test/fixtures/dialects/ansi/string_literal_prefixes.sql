@@ -0,0 +1,10 @@
+SELECT
+    E'it\'s escaped',
+    U&'d\0061t\+000061',
+    B'0101',
+    X'1F',
+    0x1F,
+    R'raw\string'
+FROM tbl;
+
+SELECT col FROM tbl WHERE col LIKE E'%\_%';
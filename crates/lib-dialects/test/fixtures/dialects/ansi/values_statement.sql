@@ -0,0 +1,5 @@
+values (1, 'a'), (2, 'b');
+
+select t.a, t.b from values (1, 'a'), (2, 'b') as t (a, b);
+
+select t.a from (values (1), (2)) as t (a);
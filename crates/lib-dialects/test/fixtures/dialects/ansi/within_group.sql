@@ -0,0 +1,12 @@
+select percentile_cont(0.5) within group (order by salary) as median_salary
+from employees;
+
+select
+    department,
+    listagg(name, ', ') within group (order by name) as names
+from employees
+group by department;
+
+select
+    percentile_disc(0.9) within group (order by salary) over (partition by department) as p90
+from employees;
@@ -0,0 +1,3 @@
+SELECT * FROM users WHERE id = @user_id;
+
+SELECT @@session.sql_mode, @@version;
@@ -0,0 +1,2 @@
+-- Block comments are not nested, the first `*/` closes the comment.
+SELECT 1 /* a /* b */ AS x;
@@ -0,0 +1,13 @@
+SELECT a, b INTO @x, @y FROM t WHERE c = 1;
+
+SELECT a, b FROM t WHERE c = 1 INTO @x, @y;
+
+SELECT COUNT(*) FROM t GROUP BY a ORDER BY a LIMIT 1 INTO total;
+
+SELECT * INTO DUMPFILE '/tmp/row.bin' FROM t LIMIT 1;
+
+SELECT a, b FROM t
+INTO OUTFILE '/tmp/result.csv'
+    CHARACTER SET utf8mb4
+    FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '"' ESCAPED BY '\\'
+    LINES STARTING BY '' TERMINATED BY '\n';
@@ -0,0 +1,17 @@
+SHOW TABLES;
+
+SHOW FULL TABLES FROM my_db LIKE 'user%';
+
+SHOW COLUMNS FROM my_table IN my_db;
+
+SHOW CREATE TABLE my_db.my_table;
+
+SHOW CREATE DATABASE my_db;
+
+SHOW DATABASES LIKE 'prod%';
+
+SHOW VARIABLES LIKE 'max_connections';
+
+SHOW GLOBAL STATUS WHERE variable_name = 'Uptime';
+
+SHOW FULL PROCESSLIST;
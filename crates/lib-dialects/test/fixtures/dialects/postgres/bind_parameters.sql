@@ -0,0 +1,3 @@
+SELECT * FROM users WHERE id = %(user_id)s AND status = %(status)s;
+
+SELECT 10 % 3, $1;
@@ -0,0 +1,3 @@
+SELECT * FROM users WHERE id = :id OR name = @name OR email = $email;
+
+INSERT INTO users (id, name) VALUES (:id, @name);
@@ -0,0 +1,3 @@
+EXPLAIN SELECT a FROM b;
+
+EXPLAIN QUERY PLAN SELECT a FROM b WHERE a = 1;
@@ -0,0 +1,3 @@
+CREATE TABLE orders_copy AS SELECT orderkey, totalprice FROM orders WITH DATA;
+
+CREATE TABLE orders_empty AS (SELECT orderkey, totalprice FROM orders) WITH NO DATA;
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::str::FromStr;
 
 use ahash::HashSet;
@@ -55,8 +56,14 @@ fn main() {
         .collect::<HashSet<String>>();
     println!("{:?}", dialects);
 
+    // Point this at sqlfluff's `test/fixtures/dialects` to compare against its corpus.
+    if let Ok(fixtures_dir) = std::env::var("SQLFLUFF_FIXTURES") {
+        compare_with_sqlfluff(Path::new(&fixtures_dir), &dialects);
+        return;
+    }
+
     // list folders in the dialects directory
-    let dialects_dir = std::path::Path::new("test/fixtures/dialects");
+    let dialects_dir = Path::new("test/fixtures/dialects");
     let dialects_dirs = dialects_dir
         .read_dir()
         .unwrap()
@@ -115,3 +122,61 @@ fn main() {
         });
     }
 }
+
+/// Parses the sqlfluff dialect fixtures in `fixtures_dir` and compares each tree
+/// with the one sqlfluff recorded for it, listing the fixtures which differ.
+fn compare_with_sqlfluff(fixtures_dir: &Path, dialects: &HashSet<String>) {
+    let mut matching = 0;
+    let mut differing = Vec::new();
+
+    for dialect_name in dialects.iter().sorted() {
+        let dialect_kind = DialectKind::from_str(dialect_name).unwrap();
+        let Some(dialect) = kind_to_dialect(&dialect_kind) else {
+            continue;
+        };
+
+        let path = fixtures_dir.join(dialect_name).join("*.sql");
+        let files = glob::glob(&path.to_string_lossy())
+            .unwrap()
+            .flatten()
+            .collect_vec();
+
+        for file in files {
+            let Ok(expected) = std::fs::read_to_string(file.with_extension("yml")) else {
+                continue;
+            };
+            let mut expected: serde_yaml::Value = serde_yaml::from_str(&expected).unwrap();
+            if let Some(expected) = expected.as_mapping_mut() {
+                expected.remove("_hash");
+            }
+
+            let sql = std::fs::read_to_string(&file).unwrap();
+            let tables = Tables::default();
+            let (tokens, errors) = Lexer::from(&dialect)
+                .lex(&tables, StringOrTemplate::String(&sql))
+                .unwrap();
+            let tree = errors
+                .is_empty()
+                .then(|| Parser::from(&dialect).parse(&tables, &tokens, None))
+                .and_then(Result::ok)
+                .flatten();
+            let actual = tree.map(|tree| {
+                serde_yaml::to_value(tree.to_serialised(true, true).as_sqlfluff_record()).unwrap()
+            });
+
+            if actual.as_ref() == Some(&expected) {
+                matching += 1;
+            } else {
+                differing.push(file);
+            }
+        }
+    }
+
+    for file in &differing {
+        println!("differs from sqlfluff: {}", file.display());
+    }
+    println!(
+        "{matching} fixtures match sqlfluff, {} differ",
+        differing.len()
+    );
+}
@@ -4,6 +4,7 @@ mod tests {
     use sqruff_lib::core::config::FluffConfig;
     use sqruff_lib::core::linter::core::Linter;
     use sqruff_lib::core::test_functions::fresh_ansi_dialect;
+    use sqruff_lib_core::dialects::base::KeywordClass;
     use sqruff_lib_core::dialects::init::DialectKind;
     use sqruff_lib_core::dialects::syntax::SyntaxKind;
     use sqruff_lib_core::parser::context::ParseContext;
@@ -216,6 +217,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dialect_ansi_keyword_class() {
+        let dialect = fresh_ansi_dialect();
+
+        assert_eq!(
+            dialect.keyword_class("select"),
+            Some(KeywordClass::Reserved)
+        );
+        assert_eq!(
+            dialect.keyword_class("Parameter"),
+            Some(KeywordClass::Unreserved)
+        );
+        assert_eq!(dialect.keyword_class("artist"), None);
+    }
+
     #[test]
     fn test_dialect_ansi_is_whitespace() {
         let lnt = Linter::new(
@@ -65,3 +65,17 @@ pub fn fix(sql: &str) -> String {
     let mut result = linter.lint_string_wrapped(sql, None, true);
     take(&mut result.paths[0].files[0]).fix_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::fix;
+
+    #[test]
+    fn test_simple_fix_applies_unsafe_fixes() {
+        // Only `sqruff fix` holds back fixes which could change the query.
+        assert_eq!(
+            fix("SELECT a FROM t WHERE b = NULL\n"),
+            "SELECT a FROM t WHERE b IS NULL\n"
+        );
+    }
+}
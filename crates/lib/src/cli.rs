@@ -2,5 +2,6 @@ pub mod formatters;
 pub mod github_annotation_native_formatter;
 pub mod json;
 pub mod json_types;
+pub mod patches;
 pub mod rules;
 pub mod utils;
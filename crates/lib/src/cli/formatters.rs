@@ -11,6 +11,7 @@ use crate::core::config::FluffConfig;
 use crate::core::linter::linted_file::LintedFile;
 
 const LIGHT_GREY: Style = AnsiColor::Black.on_default().effects(Effects::BOLD);
+const RED: Style = AnsiColor::Red.on_default();
 
 pub trait Formatter: Send + Sync {
     fn dispatch_template_header(
@@ -37,6 +38,8 @@ pub struct OutputStreamFormatter {
     output_line_length: usize,
     pub has_fail: AtomicBool,
     files_dispatched: AtomicUsize,
+    files_with_violations: AtomicUsize,
+    violations_dispatched: AtomicUsize,
 }
 
 impl Formatter for OutputStreamFormatter {
@@ -45,14 +48,27 @@ impl Formatter for OutputStreamFormatter {
             return;
         }
 
+        let violations = linted_file.get_violations(only_fixable.then_some(true));
+        let shown = violations
+            .iter()
+            .filter(|violation| !violation.ignore)
+            .count();
         let s = self.format_file_violations(
             &linted_file.path,
-            linted_file.get_violations(only_fixable.then_some(true)),
+            // Violations are positioned in the source file.
+            &linted_file.templated_file.source_str,
+            violations,
         );
 
         self.dispatch(&s);
         self.files_dispatched
             .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if shown > 0 {
+            self.files_with_violations
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.violations_dispatched
+                .fetch_add(shown, std::sync::atomic::Ordering::SeqCst);
+        }
     }
 
     fn has_fail(&self) -> bool {
@@ -60,6 +76,17 @@ impl Formatter for OutputStreamFormatter {
     }
 
     fn completion_message(&self) {
+        let violations = self
+            .violations_dispatched
+            .load(std::sync::atomic::Ordering::SeqCst);
+        if violations > 0 {
+            let files = self
+                .files_with_violations
+                .load(std::sync::atomic::Ordering::SeqCst);
+            let message = format!("Found {violations} violation(s) in {files} file(s).\n");
+            self.dispatch(&self.colorize(&message, RED));
+        }
+
         let count = self
             .files_dispatched
             .load(std::sync::atomic::Ordering::SeqCst);
@@ -88,12 +115,14 @@ impl OutputStreamFormatter {
     pub fn new(output_stream: Option<Stderr>, nocolor: bool, verbosity: i32) -> Self {
         Self {
             output_stream,
-            plain_output: should_produce_plain_output(nocolor),
+            plain_output: should_produce_plain_output(nocolor, &std::io::stderr()),
             filter_empty: true,
             verbosity,
             output_line_length: 80,
             has_fail: false.into(),
             files_dispatched: 0.into(),
+            files_with_violations: 0.into(),
+            violations_dispatched: 0.into(),
         }
     }
 
@@ -105,7 +134,12 @@ impl OutputStreamFormatter {
         }
     }
 
-    fn format_file_violations(&self, fname: &str, mut violations: Vec<SQLBaseError>) -> String {
+    fn format_file_violations(
+        &self,
+        fname: &str,
+        source: &str,
+        mut violations: Vec<SQLBaseError>,
+    ) -> String {
         let mut text_buffer = String::new();
 
         let fails = violations
@@ -132,9 +166,14 @@ impl OutputStreamFormatter {
             });
 
             for violation in violations {
+                let frame = self.format_code_frame(source, violation.line_no, violation.line_pos);
                 let text = self.format_violation(violation, self.output_line_length);
                 text_buffer.push_str(&text);
                 text_buffer.push('\n');
+                if let Some(frame) = frame {
+                    text_buffer.push_str(&frame);
+                    text_buffer.push('\n');
+                }
             }
         }
 
@@ -222,6 +261,27 @@ impl OutputStreamFormatter {
 
         out_buff
     }
+
+    /// Show the line a violation is on, with a caret under its position.
+    fn format_code_frame(&self, sql: &str, line_no: usize, line_pos: usize) -> Option<String> {
+        let line = sql.lines().nth(line_no.checked_sub(1)?)?;
+        // Positions past the end of the line point just after it, e.g. at a
+        // missing trailing newline.
+        let prefix = line
+            .chars()
+            .take(line_pos.checked_sub(1)?)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        let gutter = self.colorize("|", LIGHT_GREY);
+        let line_no = format!("{line_no:>6}");
+        let line_no = self.colorize(&line_no, LIGHT_GREY);
+        let caret = self.colorize("^", RED);
+
+        Some(format!(
+            "{line_no} {gutter} {line}\n       {gutter} {prefix}{caret}"
+        ))
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -319,6 +379,29 @@ mod tests {
         assert_eq!(escape_ansi(&f), "L:   3 | P:   3 | DESC | DESC [some-name]");
     }
 
+    #[test]
+    fn test_cli_formatters_code_frame() {
+        let formatter = mk_formatter();
+        let sql = "SELECT\n\ta ,  b\nFROM t";
+
+        let actual = formatter.format_code_frame(sql, 2, 3).unwrap();
+        assert_eq!(escape_ansi(&actual), "     2 | \ta ,  b\n       | \t ^");
+
+        let actual = formatter.format_code_frame(sql, 3, 7).unwrap();
+        assert_eq!(escape_ansi(&actual), "     3 | FROM t\n       |       ^");
+
+        assert_eq!(formatter.format_code_frame(sql, 4, 1), None);
+
+        // Positions count characters, not bytes.
+        let actual = formatter
+            .format_code_frame("SELECT 'ü€' ,a", 1, 13)
+            .unwrap();
+        assert_eq!(
+            escape_ansi(&actual),
+            "     1 | SELECT 'ü€' ,a\n       |             ^"
+        );
+    }
+
     #[test]
     fn test_cli_helpers_colorize() {
         let mut formatter = mk_formatter();
@@ -19,7 +19,7 @@ impl Formatter for JsonFormatter {
         lock.entry(linted_file.path.clone()).or_default().extend(
             violations
                 .iter()
-                .map(|err| Diagnostic::from(err.clone()))
+                .map(|err| Diagnostic::new(err.clone(), &linted_file.templated_file))
                 .collect::<Vec<_>>(),
         );
     }
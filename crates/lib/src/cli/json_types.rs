@@ -2,14 +2,19 @@ use std::collections::BTreeMap;
 
 use serde::Serialize;
 use sqruff_lib_core::errors::SQLBaseError;
+use sqruff_lib_core::templaters::base::TemplatedFile;
 
-impl From<SQLBaseError> for Diagnostic {
-    fn from(value: SQLBaseError) -> Self {
+impl Diagnostic {
+    /// Build the diagnostic for an error in `templated_file`. The range spans
+    /// the error's slice of the source file.
+    pub fn new(value: SQLBaseError, templated_file: &TemplatedFile) -> Self {
         let code = value.rule.map(|rule| rule.code.to_string());
+        let start = templated_file.get_line_pos_of_char_pos(value.source_slice.start, true);
+        let end = templated_file.get_line_pos_of_char_pos(value.source_slice.end, true);
         Diagnostic {
             range: Range {
-                start: Position::new(value.line_no as u32, value.line_pos as u32),
-                end: Position::new(value.line_no as u32, value.line_pos as u32),
+                start: Position::new(start.0 as u32, start.1 as u32),
+                end: Position::new(end.0 as u32, end.1 as u32),
             },
             message: value.description,
             severity: if value.warning {
@@ -19,6 +24,7 @@ impl From<SQLBaseError> for Diagnostic {
             },
             source: Some("sqruff".to_string()),
             code,
+            fixable: value.fixable,
             // code: todo!(),
             // source: Some(value.get_source().to_string()),
             // code: Some(DiagnosticCode {
@@ -34,9 +40,9 @@ impl From<SQLBaseError> for Diagnostic {
 /// Represents a line and character position, such as the position of the cursor.
 #[derive(Serialize)]
 struct Position {
-    /// The zero-based line value.
+    /// The one-based line value.
     line: u32,
-    /// The zero-based character value.
+    /// The one-based character value.
     character: u32,
 }
 
@@ -69,6 +75,8 @@ pub struct Diagnostic {
     source: Option<String>,
     // The diagnostic's code, which might appear in the user interface.
     code: Option<String>,
+    /// Whether `sqruff fix` can fix the error.
+    fixable: bool,
     // An optional property to describe the error code.
     // code_description: Option<CodeDescription>,
     // TODO Maybe implement
@@ -0,0 +1,245 @@
+use serde::Serialize;
+
+use crate::core::linter::linted_file::{LintedFile, SourcePatch};
+
+/// Number of unchanged lines shown around each change in a unified diff.
+const CONTEXT_LINES: usize = 3;
+
+/// A fix in the JSON patch list, replacing the bytes in `start..end` of
+/// `file` with `replacement`.
+#[derive(Debug, Serialize)]
+pub struct JsonPatch {
+    pub file: String,
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+/// Collect the fixes for the given files as a JSON patch list.
+pub fn json_patches<'a>(files: impl IntoIterator<Item = &'a LintedFile>) -> String {
+    let patches = files
+        .into_iter()
+        .flat_map(|file| {
+            file.source_patches().into_iter().map(|patch| JsonPatch {
+                file: file.path.clone(),
+                start: patch.start,
+                end: patch.end,
+                replacement: patch.replacement,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&patches).unwrap()
+}
+
+/// Render the fixes for a file as a unified diff which can be applied with
+/// `git apply` or `patch -p1`.
+pub fn unified_diff(file: &LintedFile) -> String {
+    render_unified_diff(&file.path, &file.raw_source(), &file.source_patches())
+}
+
+/// A run of changed lines, `old_lines` of the original being replaced by
+/// `new_lines`.
+struct Change<'a> {
+    old_lines: std::ops::Range<usize>,
+    new_lines: Vec<&'a str>,
+}
+
+fn render_unified_diff(path: &str, source: &str, patches: &[SourcePatch]) -> String {
+    if patches.is_empty() {
+        return String::new();
+    }
+
+    let lines = source.split_inclusive('\n').collect::<Vec<_>>();
+    let line_starts = lines
+        .iter()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+    let line_start = |line: usize| line_starts.get(line).copied().unwrap_or(source.len());
+
+    // Group the patches by the lines they touch, rewriting each group of lines
+    // in one go.
+    let mut groups: Vec<(std::ops::Range<usize>, Vec<&SourcePatch>)> = Vec::new();
+    for patch in patches {
+        let first = if lines.is_empty() {
+            0
+        } else {
+            line_of(patch.start)
+        };
+        let last = if patch.end > patch.start {
+            line_of(patch.end - 1) + 1
+        } else {
+            first + 1
+        }
+        .min(lines.len());
+
+        match groups.last_mut() {
+            Some((span, group)) if first < span.end => {
+                span.end = span.end.max(last);
+                group.push(patch);
+            }
+            _ => groups.push((first..last, vec![patch])),
+        }
+    }
+
+    let new_texts = groups
+        .iter()
+        .map(|(span, group)| {
+            let offset = line_start(span.start);
+            let mut text = String::new();
+            let mut idx = offset;
+            for patch in group {
+                text.push_str(&source[idx..patch.start]);
+                text.push_str(&patch.replacement);
+                idx = patch.end;
+            }
+            text.push_str(&source[idx..line_start(span.end)]);
+            text
+        })
+        .collect::<Vec<_>>();
+
+    let changes = groups
+        .iter()
+        .zip(&new_texts)
+        .map(|((span, _), text)| Change {
+            old_lines: span.clone(),
+            new_lines: text.split_inclusive('\n').collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+    let mut line_delta: isize = 0;
+    let mut idx = 0;
+    while idx < changes.len() {
+        // Changes whose context would overlap share a hunk.
+        let mut end = idx + 1;
+        while end < changes.len()
+            && changes[end].old_lines.start - changes[end - 1].old_lines.end <= 2 * CONTEXT_LINES
+        {
+            end += 1;
+        }
+
+        let hunk_start = changes[idx].old_lines.start.saturating_sub(CONTEXT_LINES);
+        let hunk_end = (changes[end - 1].old_lines.end + CONTEXT_LINES).min(lines.len());
+
+        let mut body = String::new();
+        let mut old_len = 0;
+        let mut new_len = 0;
+        let push_line = |body: &mut String, prefix: char, line: &str| {
+            body.push(prefix);
+            body.push_str(line);
+            if !line.ends_with('\n') {
+                body.push_str("\n\\ No newline at end of file\n");
+            }
+        };
+
+        let mut line = hunk_start;
+        for change in &changes[idx..end] {
+            for context in &lines[line..change.old_lines.start] {
+                push_line(&mut body, ' ', context);
+            }
+            for old in &lines[change.old_lines.clone()] {
+                push_line(&mut body, '-', old);
+            }
+            for new in &change.new_lines {
+                push_line(&mut body, '+', new);
+            }
+
+            old_len += change.old_lines.start - line + change.old_lines.len();
+            new_len += change.old_lines.start - line + change.new_lines.len();
+            line = change.old_lines.end;
+        }
+        for context in &lines[line..hunk_end] {
+            push_line(&mut body, ' ', context);
+        }
+        old_len += hunk_end - line;
+        new_len += hunk_end - line;
+
+        // An empty range is numbered by the line before it.
+        let number = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+        let new_start = hunk_start.checked_add_signed(line_delta).unwrap();
+        diff.push_str(&format!(
+            "@@ -{},{old_len} +{},{new_len} @@\n",
+            number(hunk_start, old_len),
+            number(new_start, new_len),
+        ));
+        diff.push_str(&body);
+
+        line_delta += new_len as isize - old_len as isize;
+        idx = end;
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(start: usize, end: usize, replacement: &str) -> SourcePatch {
+        SourcePatch {
+            start,
+            end,
+            replacement: replacement.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_unified_diff_merges_nearby_changes() {
+        let source = "select\n    a,\n    b\nfrom t\nwhere x\n  and y\norder by 1\nlimit 1\n\n\n\nunion all\nselect 2\n";
+        let patches = [
+            patch(0, 6, "SELECT"),
+            patch(20, 24, "FROM"),
+            patch(75, 81, "SELECT"),
+        ];
+
+        assert_eq!(
+            render_unified_diff("q.sql", source, &patches),
+            [
+                "--- a/q.sql",
+                "+++ b/q.sql",
+                "@@ -1,7 +1,7 @@",
+                "-select",
+                "+SELECT",
+                "     a,",
+                "     b",
+                "-from t",
+                "+FROM t",
+                " where x",
+                "   and y",
+                " order by 1",
+                "@@ -10,4 +10,4 @@",
+                " ",
+                " ",
+                " union all",
+                "-select 2",
+                "+SELECT 2",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_without_trailing_newline() {
+        let patches = [patch(8, 9, "\n")];
+
+        assert_eq!(
+            render_unified_diff("q.sql", "SELECT 1 FROM t", &patches),
+            "--- a/q.sql
++++ b/q.sql
+@@ -1,1 +1,2 @@
+-SELECT 1 FROM t
+\\ No newline at end of file
++SELECT 1
++FROM t
+\\ No newline at end of file
+"
+        );
+    }
+}
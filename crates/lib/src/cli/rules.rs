@@ -26,7 +26,7 @@ impl RulesFormatter {
     pub fn new(nocolor: bool) -> Self {
         Self {
             rules: rules(),
-            plain_output: should_produce_plain_output(nocolor),
+            plain_output: should_produce_plain_output(nocolor, &std::io::stdout()),
         }
     }
 
@@ -48,8 +48,9 @@ impl RulesFormatter {
         let name = self.colorize(rule.name(), BLUE);
         let decription = &rule.description();
         let groups = self.colorize(group.as_str(), YELLOW);
+        let fixable = if rule.is_fixable() { "yes" } else { "no" };
 
-        format!("{code}:\t[{name}] {decription}\n\tgroups: {groups}")
+        format!("{code}:\t[{name}] {decription}\n\tgroups: {groups}\n\tfixable: {fixable}")
     }
 
     pub fn rules_info(&self) {
@@ -2,8 +2,10 @@ use anstyle::Style;
 use std::borrow::Cow;
 use std::io::IsTerminal;
 
-pub fn should_produce_plain_output(nocolor: bool) -> bool {
-    nocolor || !std::io::stdout().is_terminal()
+/// Whether to leave out colours when writing to `stream`, because they were
+/// turned off or the stream isn't a terminal.
+pub fn should_produce_plain_output(nocolor: bool, stream: &impl IsTerminal) -> bool {
+    nocolor || !stream.is_terminal()
 }
 
 pub fn colorize_helper(nocolor: bool, s: &str, style: Style) -> Cow<'_, str> {
@@ -8,11 +8,31 @@ use itertools::Itertools;
 use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::init::{DialectKind, dialect_readout};
 use sqruff_lib_core::errors::SQLFluffUserError;
+use sqruff_lib_core::parser::lexer::Matcher;
 use sqruff_lib_core::parser::parser::Parser;
-use sqruff_lib_dialects::kind_to_dialect;
+use sqruff_lib_dialects::{bind_parameter_matcher, kind_to_dialect};
 
 use crate::utils::reflow::config::ReflowConfig;
 
+/// Returns the lexer matchers for the `bind_parameter_styles` config option,
+/// which is either a single style or a comma separated list of them.
+fn bind_parameter_matchers(styles: &Value) -> Result<Vec<Matcher>, SQLFluffUserError> {
+    styles
+        .as_array()
+        .unwrap_or_default()
+        .iter()
+        .map(|style| {
+            let style = style.as_string().unwrap_or_default().trim();
+            bind_parameter_matcher(style).ok_or_else(|| {
+                SQLFluffUserError::new(format!(
+                    "Unknown bind parameter style '{style}'. Available styles: colon, \
+                     numeric_colon, dollar, at, pyformat"
+                ))
+            })
+        })
+        .collect()
+}
+
 /// split_comma_separated_string takes a string and splits it on commas and
 /// trims and filters out empty strings.
 pub fn split_comma_separated_string(raw_str: &str) -> Value {
@@ -147,10 +167,21 @@ impl FluffConfig {
             .map(|it| it.as_string().unwrap().to_owned())
             .collect();
 
+        let mut dialect =
+            dialect.expect("Dialect is disabled. Please enable the corresponding feature.");
+        // Unknown styles are reported by `verify_bind_parameter_styles`.
+        let matchers =
+            bind_parameter_matchers(&configs["core"]["bind_parameter_styles"]).unwrap_or_default();
+        if !matchers.is_empty() {
+            // Ahead of the dialect's own matchers, so that e.g. `:name` isn't lexed
+            // as a colon.
+            let first = dialect.lexer_matchers()[0].name();
+            dialect.extend_lexer(matchers, first);
+        }
+
         let mut this = Self {
             raw: configs,
-            dialect: dialect
-                .expect("Dialect is disabled. Please enable the corresponding feature."),
+            dialect,
             extra_config_path,
             _configs: AHashMap::new(),
             indentation: indentation.unwrap_or_default(),
@@ -189,7 +220,11 @@ impl FluffConfig {
             }
         }
 
-        Ok(FluffConfig::new(config, extra_config_path, None))
+        let config = FluffConfig::new(config, extra_config_path, None);
+        match config.verify_bind_parameter_styles() {
+            Some(error) => Err(error),
+            None => Ok(config),
+        }
     }
 
     pub fn from_kwargs(
@@ -239,6 +274,12 @@ command. Available dialects: {}",
         )))
     }
 
+    /// Check that every style in `bind_parameter_styles` is known, raising an
+    /// error if not.
+    pub fn verify_bind_parameter_styles(&self) -> Option<SQLFluffUserError> {
+        bind_parameter_matchers(&self.raw["core"]["bind_parameter_styles"]).err()
+    }
+
     pub fn get_dialect(&self) -> &Dialect {
         &self.dialect
     }
@@ -627,6 +668,13 @@ impl<'a> From<&'a FluffConfig> for Parser<'a> {
             .iter()
             .map(|(key, value)| (key.clone(), value.to_bool()))
             .collect();
+        let limit = |key| {
+            config
+                .get(key, "core")
+                .as_int()
+                .map_or(0, |limit| limit.max(0) as usize)
+        };
         Self::new(dialect, indentation_config)
+            .with_limits(limit("max_parse_depth"), limit("max_parse_matches"))
     }
 }
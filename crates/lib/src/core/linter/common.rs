@@ -1,7 +1,11 @@
+use std::ops::Range;
+
 use sqruff_lib_core::errors::{SQLBaseError, SQLTemplaterError};
 use sqruff_lib_core::parser::segments::base::ErasedSegment;
 use sqruff_lib_core::templaters::base::TemplatedFile;
 
+use crate::core::linter::linted_file::common_affixes;
+
 /// An object to store the result of a templated file/string.
 ///
 /// This is notable as it's the intermediate state between what happens
@@ -12,6 +16,7 @@ pub struct RenderedFile {
     pub templater_violations: Vec<SQLTemplaterError>,
     pub(crate) filename: String,
     pub source_str: String,
+    pub(crate) source_format: SourceFormat,
 }
 
 /// An object to store the result of parsing a string.
@@ -22,4 +27,143 @@ pub struct ParsedString {
     pub templated_file: TemplatedFile,
     pub filename: String,
     pub source_str: String,
+    pub source_format: SourceFormat,
+}
+
+/// Details of the raw file which are normalised away before templating, and
+/// restored when the fixed file is written back out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceFormat {
+    /// The file started with a UTF-8 byte order mark.
+    pub bom: bool,
+    /// The file's first line break was `\r\n`, so line breaks added by fixes
+    /// are written that way too.
+    pub crlf: bool,
+    /// The line breaks which weren't a plain `\n` in the raw file, as their
+    /// offset in the normalised source and their original text.
+    line_breaks: Vec<(usize, &'static str)>,
+}
+
+impl SourceFormat {
+    const BOM: char = '\u{feff}';
+
+    /// Detect the format of a raw file.
+    pub fn detect(raw: &str) -> Self {
+        let bom = raw.starts_with(Self::BOM);
+        let raw = Self::strip_bom(raw);
+
+        let mut removed = 0;
+        let line_breaks = lazy_regex::regex!("\r\n|\r")
+            .find_iter(raw)
+            .map(|line_break| {
+                let offset = line_break.start() - removed;
+                removed += line_break.len() - 1;
+
+                let text = if line_break.len() == 2 { "\r\n" } else { "\r" };
+                (offset, text)
+            })
+            .collect();
+
+        Self {
+            bom,
+            crlf: raw.find('\n').is_some_and(|idx| raw[..idx].ends_with('\r')),
+            line_breaks,
+        }
+    }
+
+    /// Strip the byte order mark, if any.
+    pub fn strip_bom(raw: &str) -> &str {
+        raw.strip_prefix(Self::BOM).unwrap_or(raw)
+    }
+
+    /// Restore the byte order mark and line endings on the normalised source.
+    pub fn restore(&self, normalised: &str) -> String {
+        let mut restored = self.restore_slice(normalised, 0..normalised.len());
+
+        if self.bom {
+            restored.insert(0, Self::BOM);
+        }
+
+        restored
+    }
+
+    /// Restore the original line endings of `range` of the normalised source.
+    pub fn restore_slice(&self, normalised: &str, range: Range<usize>) -> String {
+        let start = range.start;
+        replace_line_breaks(&normalised[range], |idx| self.line_break_at(start + idx))
+    }
+
+    /// Restore the line endings of a fix replacing `range` of the normalised
+    /// source with `replacement`. Line breaks in the text the fix leaves alone
+    /// keep their original endings, and those in the text it changes take the
+    /// endings of the ones they replace in order. Any left over are new, and
+    /// are written as the file's first line break.
+    pub fn restore_replacement(
+        &self,
+        normalised: &str,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        let original = &normalised[range.clone()];
+        let (prefix, suffix) = common_affixes(original, replacement);
+        let changed = prefix..replacement.len() - suffix;
+        let original_changed = range.start + prefix..range.end - suffix;
+
+        let mut changed_breaks = normalised[original_changed.clone()]
+            .match_indices('\n')
+            .map(|(idx, _)| self.line_break_at(original_changed.start + idx));
+        let added_break = if self.crlf { "\r\n" } else { "\n" };
+
+        replace_line_breaks(replacement, |idx| {
+            if idx < changed.start {
+                self.line_break_at(range.start + idx)
+            } else if idx >= changed.end {
+                self.line_break_at(range.end - (replacement.len() - idx))
+            } else {
+                changed_breaks.next().unwrap_or(added_break)
+            }
+        })
+    }
+
+    /// Map an offset into the normalised source to the same position in the
+    /// raw file, accounting for the byte order mark and every `\r\n` before
+    /// it.
+    pub fn raw_offset(&self, idx: usize) -> usize {
+        let bom_len = if self.bom { Self::BOM.len_utf8() } else { 0 };
+        let preceding = self
+            .line_breaks
+            .partition_point(|&(offset, _)| offset < idx);
+        let carriage_returns = self.line_breaks[..preceding]
+            .iter()
+            .filter(|&&(_, text)| text == "\r\n")
+            .count();
+
+        bom_len + idx + carriage_returns
+    }
+
+    /// The original text of the line break at `idx` of the normalised source.
+    fn line_break_at(&self, idx: usize) -> &'static str {
+        self.line_breaks
+            .binary_search_by_key(&idx, |&(offset, _)| offset)
+            .map_or("\n", |pos| self.line_breaks[pos].1)
+    }
+}
+
+/// Replace each `\n` in `normalised` with the line break returned for its
+/// offset.
+fn replace_line_breaks(
+    normalised: &str,
+    mut line_break: impl FnMut(usize) -> &'static str,
+) -> String {
+    let mut replaced = String::with_capacity(normalised.len());
+    let mut last = 0;
+
+    for (idx, _) in normalised.match_indices('\n') {
+        replaced.push_str(&normalised[last..idx]);
+        replaced.push_str(line_break(idx));
+        last = idx + 1;
+    }
+
+    replaced.push_str(&normalised[last..]);
+    replaced
 }
@@ -7,10 +7,10 @@ use std::sync::{Arc, OnceLock};
 use super::linted_dir::LintedDir;
 use crate::cli::formatters::Formatter;
 use crate::core::config::FluffConfig;
-use crate::core::linter::common::{ParsedString, RenderedFile};
+use crate::core::linter::common::{ParsedString, RenderedFile, SourceFormat};
 use crate::core::linter::linted_file::LintedFile;
 use crate::core::linter::linting_result::LintingResult;
-use crate::core::rules::base::{ErasedRule, LintPhase, RulePack};
+use crate::core::rules::base::{ErasedRule, LintPhase, RuleGroups, RulePack};
 use crate::core::rules::noqa::IgnoreMask;
 use crate::rules::get_ruleset;
 use crate::templaters::raw::RawTemplater;
@@ -22,11 +22,12 @@ use smol_str::{SmolStr, ToSmolStr};
 use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
 use sqruff_lib_core::errors::{
-    SQLBaseError, SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError, SqlError,
+    ErrorStructRule, SQLBaseError, SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError,
+    SqlError,
 };
-use sqruff_lib_core::helpers;
+use sqruff_lib_core::helpers::{self, Config};
 use sqruff_lib_core::lint_fix::LintFix;
-use sqruff_lib_core::linter::compute_anchor_edit_info;
+use sqruff_lib_core::linter::compute_non_conflicting_anchor_edit_info;
 use sqruff_lib_core::parser::lexer::StringOrTemplate;
 use sqruff_lib_core::parser::parser::Parser;
 use sqruff_lib_core::parser::segments::base::{ErasedSegment, Tables};
@@ -39,6 +40,11 @@ pub struct Linter {
     formatter: Option<Arc<dyn Formatter>>,
     templater: &'static dyn Templater,
     rules: OnceLock<Vec<ErasedRule>>,
+    /// The codes of the rules whose fixes are applied, all of them when `None`.
+    fixable_rules: Option<AHashSet<&'static str>>,
+    /// Whether fixes which could change the meaning of the query are applied,
+    /// rather than only those of the rules with safe fixes.
+    unsafe_fixes: bool,
 
     /// include_parse_errors is a flag to indicate whether to include parse errors in the output
     include_parse_errors: bool,
@@ -60,6 +66,8 @@ impl Linter {
             formatter,
             templater,
             rules: OnceLock::new(),
+            fixable_rules: None,
+            unsafe_fixes: true,
             include_parse_errors,
         }
     }
@@ -236,6 +244,7 @@ impl Linter {
             templated_file: parsed_string.templated_file,
             violations,
             ignore_mask,
+            source_format: parsed_string.source_format,
         };
 
         if let Some(formatter) = &self.formatter {
@@ -261,10 +270,18 @@ impl Linter {
         };
         let mut previous_versions: AHashSet<(SmolStr, Vec<SourceFix>)> =
             [(tree.raw().to_smolstr(), vec![])].into_iter().collect();
+        let mut deferred_fixes: AHashMap<&str, Vec<LintFix>> = AHashMap::new();
 
         // If we are fixing then we want to loop up to the runaway_limit, otherwise just
         // once for linting.
-        let loop_limit = if fix { 10 } else { 1 };
+        let loop_limit = if fix {
+            self.config
+                .get("runaway_limit", "core")
+                .as_int()
+                .map_or(10, |limit| limit.max(1) as usize)
+        } else {
+            1
+        };
         // Look for comment segments which might indicate lines to ignore.
         let (ignore_mask, violations): (Option<IgnoreMask>, Vec<SQLBaseError>) = {
             let disable_noqa = self
@@ -280,6 +297,11 @@ impl Linter {
             }
         };
         initial_linting_errors.extend(violations.into_iter().map_into());
+        let ignore_templated_areas = self
+            .config
+            .get("ignore_templated_areas", "core")
+            .as_bool()
+            .unwrap_or(true);
 
         for phase in phases {
             let mut rules_this_phase = if phases.len() > 1 {
@@ -295,25 +317,27 @@ impl Linter {
                 self.rules()
             };
 
-            for loop_ in 0..(if *phase == LintPhase::Main {
+            let phase_loop_limit = if *phase == LintPhase::Main {
                 loop_limit
             } else {
                 2
-            }) {
+            };
+
+            for loop_ in 0..phase_loop_limit {
                 let is_first_linter_pass = *phase == phases[0] && loop_ == 0;
-                let mut changed = false;
+                let mut changed_by = Vec::new();
 
                 if is_first_linter_pass {
                     rules_this_phase = self.rules();
                 }
 
-                let last_fixes = Vec::new();
                 for rule in rules_this_phase {
                     // Performance: After first loop pass, skip rules that don't do fixes. Any
                     // results returned won't be seen by the user anyway (linting errors ADDED by
                     // rules changing SQL, are not reported back to the user - only initial linting
                     // errors), so there's absolutely no reason to run them.
-                    if fix && !is_first_linter_pass && !rule.is_fix_compatible() {
+                    let applies_fixes = self.applies_fixes(rule);
+                    if fix && !is_first_linter_pass && !(rule.is_fixable() && applies_fixes) {
                         continue;
                     }
 
@@ -324,7 +348,7 @@ impl Linter {
                         tree.clone(),
                         &self.config,
                     );
-                    let linting_errors: Vec<SQLLintError> = linting_errors
+                    let mut linting_errors: Vec<SQLLintError> = linting_errors
                         .into_iter()
                         .filter(|error| {
                             !ignore_mask
@@ -333,54 +357,116 @@ impl Linter {
                         })
                         .collect();
 
+                    if !applies_fixes {
+                        for error in &mut linting_errors {
+                            error.fixes.clear();
+                            error.fixable = false;
+                        }
+                    }
+
                     if is_first_linter_pass {
-                        initial_linting_errors.extend(linting_errors.clone());
+                        initial_linting_errors.extend(
+                            linting_errors
+                                .iter()
+                                .filter(|error| {
+                                    !ignore_templated_areas
+                                        || rule.targets_templated()
+                                        || !error.in_templated_region
+                                })
+                                .cloned(),
+                        );
                     }
 
-                    let fixes: Vec<LintFix> = linting_errors
+                    let mut fixes: Vec<LintFix> = linting_errors
                         .into_iter()
                         .flat_map(|linting_error| linting_error.clone().fixes.clone())
                         .collect();
 
-                    if fix && !fixes.is_empty() {
-                        // Do some sanity checks on the fixes before applying.
-                        // let anchor_info = BaseSegment.compute_anchor_edit_info(fixes);
-
-                        // This is the happy path. We have fixes, now we want to apply them.
-
-                        if fixes == last_fixes {
-                            eprintln!(
-                                "One fix for {} not applied, it would re-cause the same error.",
-                                rule.code()
-                            );
-                            continue;
+                    if let Some(deferred) = deferred_fixes.remove(rule.code()) {
+                        // Retry the fixes held back on the previous loop, as long as the
+                        // segments they edit are still in the tree unchanged.
+                        let segments: AHashMap<u32, ErasedSegment> = tree
+                            .recursive_crawl_all(false)
+                            .into_iter()
+                            .map(|segment| (segment.id(), segment))
+                            .collect();
+
+                        for mut deferred_fix in deferred {
+                            let Some(anchor) = segments.get(&deferred_fix.anchor.id()) else {
+                                continue;
+                            };
+                            if anchor.raw() != deferred_fix.anchor.raw() {
+                                continue;
+                            }
+
+                            // The rule may well have reported the same fix again, with
+                            // segments of its own.
+                            deferred_fix.anchor = anchor.clone();
+                            if !fixes.iter().any(|fix| is_same_edit(fix, &deferred_fix)) {
+                                fixes.push(deferred_fix);
+                            }
                         }
+                    }
 
-                        let mut anchor_info = compute_anchor_edit_info(fixes.into_iter());
+                    if fix && !fixes.is_empty() {
+                        // Fixes which conflict with one another are held back until the
+                        // others have been applied.
+                        let (mut anchor_info, deferred) =
+                            compute_non_conflicting_anchor_edit_info(fixes.into_iter());
                         let (new_tree, _, _, _valid) = tree.apply_fixes(&mut anchor_info);
 
-                        if false {
-                            println!(
-                                "Fixes for {rule:?} not applied, as it would result in an \
-                                 unparsable file. Please report this as a bug with a minimal \
-                                 query which demonstrates this warning.",
-                            );
-                        }
-
                         let loop_check_tuple =
                             (new_tree.raw().to_smolstr(), new_tree.get_source_fixes());
 
                         if previous_versions.insert(loop_check_tuple) {
                             tree = new_tree;
-                            changed = true;
+                            if !deferred.is_empty() {
+                                deferred_fixes.insert(rule.code(), deferred);
+                            }
+                            changed_by.push(rule);
                             continue;
                         }
+
+                        // The fixes would take the tree back to a version we've already seen,
+                        // applying them would just cycle between the same fixes.
+                        tracing::warn!(
+                            "One fix for {} not applied, it would re-cause the same error.",
+                            rule.code()
+                        );
                     }
                 }
 
-                if fix && !changed {
+                if fix && changed_by.is_empty() {
                     break;
                 }
+
+                if fix && loop_ + 1 == phase_loop_limit {
+                    // The rules were still changing the file on the last loop, so report them
+                    // rather than leaving the file half fixed without a word.
+                    initial_linting_errors.extend(
+                        changed_by
+                            .into_iter()
+                            .unique_by(|rule| rule.code())
+                            .map(|rule| {
+                                SQLLintError::new(
+                                    &format!(
+                                        "Loop limit on fixes reached [{phase_loop_limit}]. Fixes \
+                                         for {} did not converge.",
+                                        rule.code()
+                                    ),
+                                    tree.clone(),
+                                    false,
+                                    Vec::new(),
+                                )
+                                .config(|this| {
+                                    this.rule = Some(ErrorStructRule {
+                                        name: rule.name(),
+                                        code: rule.code(),
+                                    })
+                                })
+                            }),
+                    );
+                }
             }
         }
 
@@ -394,7 +480,8 @@ impl Linter {
         filename: String,
         config: &FluffConfig,
     ) -> Result<RenderedFile, SQLFluffUserError> {
-        let sql = Self::normalise_newlines(sql);
+        let source_format = SourceFormat::detect(sql);
+        let sql = Self::normalise_newlines(SourceFormat::strip_bom(sql));
 
         if let Some(error) = config.verify_dialect_specified() {
             return Err(error);
@@ -410,6 +497,7 @@ impl Linter {
                 templater_violations,
                 filename,
                 source_str: sql.to_string(),
+                source_format,
             }),
             Err(err) => Err(SQLFluffUserError::new(format!(
                 "Failed to template file {} with error {:?}",
@@ -461,6 +549,7 @@ impl Linter {
             templated_file: rendered.templated_file,
             filename: rendered.filename,
             source_str: rendered.source_str,
+            source_format: rendered.source_format,
         }
     }
 
@@ -491,9 +580,16 @@ impl Linter {
                     true,
                 );
 
-                violations.extend(unparsables.into_iter().map(|segment| SQLParseError {
-                    description: "Unparsable section".into(),
-                    segment: segment.into(),
+                violations.extend(unparsables.into_iter().map(|segment| {
+                    let description = match segment.expected() {
+                        Some(expected) => format!("Unparsable section: expected {expected}"),
+                        None => "Unparsable section".into(),
+                    };
+
+                    SQLParseError {
+                        description,
+                        segment: segment.into(),
+                    }
                 }));
             }
         };
@@ -679,6 +775,64 @@ impl Linter {
         &mut self.config
     }
 
+    /// Restricts the linter to the fixable layout and capitalisation rules
+    /// selected by the config, so fixing only ever changes how the SQL is
+    /// formatted.
+    pub fn formatting_only(mut self) -> Self {
+        let rules = self
+            .get_rulepack()
+            .rules
+            .into_iter()
+            .filter(|rule| {
+                rule.is_fixable()
+                    && rule.groups().iter().any(|group| {
+                        matches!(group, RuleGroups::Layout | RuleGroups::Capitalisation)
+                    })
+            })
+            .collect_vec();
+        self.rules = OnceLock::from(rules);
+        self
+    }
+
+    /// Only applies the fixes of the rules matching one of `selectors`, each
+    /// being a rule code, a code prefix such as `LT`, a rule name or a group.
+    /// Errors from the other rules are still reported, as unfixable.
+    pub fn fixable_rules(mut self, selectors: &[String]) -> Result<Self, String> {
+        let all_rules = crate::rules::rules();
+        let mut codes = AHashSet::new();
+
+        for selector in selectors {
+            let matched = all_rules
+                .iter()
+                .filter(|rule| rule_matches(rule, selector))
+                .map(|rule| rule.code())
+                .collect_vec();
+            if matched.is_empty() {
+                return Err(format!("No rules match '{selector}'"));
+            }
+            codes.extend(matched);
+        }
+
+        self.fixable_rules = Some(codes);
+        Ok(self)
+    }
+
+    /// Sets whether fixes which could change the meaning of the query are
+    /// applied. They are by default, errors from rules without safe fixes are
+    /// reported as unfixable otherwise.
+    pub fn unsafe_fixes(mut self, enabled: bool) -> Self {
+        self.unsafe_fixes = enabled;
+        self
+    }
+
+    fn applies_fixes(&self, rule: &ErasedRule) -> bool {
+        (self.unsafe_fixes || rule.has_safe_fixes())
+            && self
+                .fixable_rules
+                .as_ref()
+                .is_none_or(|codes| codes.contains(rule.code()))
+    }
+
     pub fn rules(&self) -> &[ErasedRule] {
         self.rules.get_or_init(|| self.get_rulepack().rules)
     }
@@ -692,12 +846,48 @@ impl Linter {
     }
 }
 
+fn rule_matches(rule: &ErasedRule, selector: &str) -> bool {
+    let code = rule.code();
+    code.get(..selector.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(selector))
+        || rule.name() == selector
+        || rule.groups().iter().any(|group| group.as_ref() == selector)
+}
+
+/// Whether two fixes make the same edit at the same place in the tree, however
+/// their segments were built.
+fn is_same_edit(fix: &LintFix, other: &LintFix) -> bool {
+    let position = |fix: &LintFix| {
+        fix.anchor
+            .get_position_marker()
+            .map(|marker| marker.working_loc())
+    };
+
+    fix.edit_type == other.edit_type
+        && fix.anchor.get_type() == other.anchor.get_type()
+        && fix.anchor.raw() == other.anchor.raw()
+        && position(fix) == position(other)
+        && fix.edit.len() == other.edit.len()
+        && fix
+            .edit
+            .iter()
+            .zip(&other.edit)
+            .all(|(edit, other)| edit.raw() == other.raw())
+}
+
 #[cfg(test)]
 mod tests {
-    use sqruff_lib_core::parser::segments::base::Tables;
+    use ahash::AHashMap;
+    use itertools::Itertools;
+    use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+    use sqruff_lib_core::lint_fix::LintFix;
+    use sqruff_lib_core::parser::segments::base::{SegmentBuilder, Tables};
 
-    use crate::core::config::FluffConfig;
+    use crate::core::config::{FluffConfig, Value};
     use crate::core::linter::core::Linter;
+    use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+    use crate::core::rules::context::RuleContext;
+    use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler, SegmentSeekerCrawler};
 
     fn normalise_paths(paths: Vec<String>) -> Vec<String> {
         paths
@@ -819,6 +1009,335 @@ mod tests {
         assert!(parsed.violations.is_empty());
     }
 
+    #[test]
+    fn test_linter_unparsable_trailing_content() {
+        let linter = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            true,
+        );
+        let tables = Tables::default();
+        let parsed = linter
+            .parse_string(&tables, "SELECT 1;\nFOO BAR BAZ\n", None)
+            .unwrap();
+
+        assert_eq!(parsed.violations.len(), 1);
+        let violation = &parsed.violations[0];
+        assert_eq!(violation.description, "Unparsable section");
+        assert_eq!((violation.line_no, violation.line_pos), (2, 1));
+    }
+
+    #[test]
+    fn test_linter_unparsable_expected_tokens() {
+        let linter = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            true,
+        );
+        let tables = Tables::default();
+        let parsed = linter
+            .parse_string(&tables, "SELECT (a + ) FROM tbl;\n", None)
+            .unwrap();
+
+        assert_eq!(parsed.violations.len(), 1);
+        let violation = &parsed.violations[0];
+        assert_eq!(violation.description, "Unparsable section: expected )");
+        assert_eq!((violation.line_no, violation.line_pos), (1, 11));
+    }
+
+    #[test]
+    fn test_linter_ignore_templated_areas() {
+        let config = |ignore_templated_areas| {
+            FluffConfig::from_source(
+                &format!(
+                    "[sqlfluff]\ntemplater = placeholder\nrules = AL03\nignore_templated_areas \
+                     = {ignore_templated_areas}\n\n[sqlfluff:templater:placeholder]\nparam_style \
+                     = colon\nexpr = a + 1\n"
+                ),
+                None,
+            )
+        };
+        let sql = "SELECT :expr, b + 1 FROM tbl\n";
+
+        let linter = Linter::new(config("True"), None, None, false);
+        let violations = linter.lint_string(sql, None, false).get_violations(None);
+        assert_eq!(violations.len(), 1);
+        assert!(!violations[0].in_templated_region);
+
+        let linter = Linter::new(config("False"), None, None, false);
+        let violations = linter.lint_string(sql, None, false).get_violations(None);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].in_templated_region);
+        assert!(!violations[1].in_templated_region);
+    }
+
+    /// Reports on the literal `FROM` keyword, with a fix rewriting the first
+    /// templated segment.
+    #[derive(Debug, Clone)]
+    struct RuleTemplatedFix;
+
+    impl Rule for RuleTemplatedFix {
+        fn load_from_config(
+            &self,
+            _config: &AHashMap<String, Value>,
+        ) -> Result<ErasedRule, String> {
+            Ok(RuleTemplatedFix.erased())
+        }
+
+        fn name(&self) -> &'static str {
+            "test.templated_fix"
+        }
+
+        fn description(&self) -> &'static str {
+            "Templated code is always wrong."
+        }
+
+        fn long_description(&self) -> &'static str {
+            ""
+        }
+
+        fn groups(&self) -> &'static [RuleGroups] {
+            &[RuleGroups::All]
+        }
+
+        fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+            let raw_segments = context.segment.get_raw_segments();
+            let from = raw_segments.iter().find(|it| it.raw() == "FROM").unwrap();
+            let templated = raw_segments
+                .iter()
+                .find(|it| it.is_code() && !it.get_position_marker().unwrap().is_literal())
+                .unwrap();
+            let replacement =
+                SegmentBuilder::token(context.tables.next_id(), "x", SyntaxKind::NakedIdentifier)
+                    .finish();
+
+            vec![LintResult::new(
+                from.clone().into(),
+                vec![LintFix::replace(templated.clone(), vec![replacement], None)],
+                None,
+                None,
+            )]
+        }
+
+        fn is_fixable(&self) -> bool {
+            false
+        }
+
+        fn crawl_behaviour(&self) -> Crawler {
+            RootOnlyCrawler.into()
+        }
+    }
+
+    #[test]
+    fn test_linter_template_conflicting_fixes() {
+        let config = |ignore_templated_areas| {
+            FluffConfig::from_source(
+                &format!(
+                    "[sqlfluff]\ntemplater = placeholder\n\
+                     ignore_templated_areas = {ignore_templated_areas}\n\n\
+                     [sqlfluff:templater:placeholder]\nparam_style = colon\nexpr = a + 1\n"
+                ),
+                None,
+            )
+        };
+        let sql = "SELECT :expr FROM tbl\n";
+
+        // The error is dropped along with its fix when templated areas are ignored.
+        let linter = Linter::new(config("True"), None, None, false);
+        linter.rules.set(vec![RuleTemplatedFix.erased()]).unwrap();
+        let violations = linter.lint_string(sql, None, false).get_violations(None);
+        assert_eq!(violations, []);
+
+        // Otherwise it's reported, but the fix isn't offered.
+        let linter = Linter::new(config("False"), None, None, false);
+        linter.rules.set(vec![RuleTemplatedFix.erased()]).unwrap();
+        let linted = linter.lint_string(sql, None, true);
+        let violations = linted.get_violations(None);
+        assert_eq!(violations.len(), 1);
+        assert!(!violations[0].in_templated_region);
+        assert!(!violations[0].fixable);
+        assert_eq!(linted.fix_string(), sql);
+    }
+
+    #[test]
+    fn test_linter_unclosed_bracket() {
+        let linter = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            true,
+        );
+        let tables = Tables::default();
+        let parsed = linter
+            .parse_string(&tables, "SELECT 1\nFROM t\nWHERE a = (2\n", None)
+            .unwrap();
+
+        assert!(parsed.tree.is_none());
+        assert_eq!(parsed.violations.len(), 1);
+        assert_eq!(
+            parsed.violations[0].description,
+            "Unclosed bracket '(' opened at line 3, position 11."
+        );
+        assert_eq!(
+            (parsed.violations[0].line_no, parsed.violations[0].line_pos),
+            (3, 11)
+        );
+    }
+
+    #[test]
+    fn test_linter_bind_parameter_styles() {
+        let sql = "SELECT * FROM t WHERE a = :a AND b = $b AND c = @c;\n";
+        let tables = Tables::default();
+
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\ndialect = postgres\n", None),
+            None,
+            None,
+            true,
+        );
+        let parsed = linter.parse_string(&tables, sql, None).unwrap();
+        assert!(!parsed.violations.is_empty());
+
+        let linter = Linter::new(
+            FluffConfig::from_source(
+                "[sqlfluff]\ndialect = postgres\nbind_parameter_styles = colon,dollar,at\n",
+                None,
+            ),
+            None,
+            None,
+            true,
+        );
+        let parsed = linter.parse_string(&tables, sql, None).unwrap();
+        assert_eq!(parsed.violations, []);
+
+        let parameters = parsed
+            .tree
+            .unwrap()
+            .recursive_crawl(
+                const { &SyntaxSet::single(SyntaxKind::Parameter) },
+                true,
+                &SyntaxSet::EMPTY,
+                true,
+            )
+            .into_iter()
+            .map(|segment| segment.raw().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(parameters, [":a", "$b", "@c"]);
+    }
+
+    #[test]
+    fn test_linter_bind_parameter_styles_single() {
+        let config = FluffConfig::from_source(
+            "[sqlfluff]\ndialect = postgres\nbind_parameter_styles = colon\n",
+            None,
+        );
+        assert!(config.verify_bind_parameter_styles().is_none());
+
+        let linter = Linter::new(config, None, None, true);
+        let tables = Tables::default();
+        let parsed = linter
+            .parse_string(&tables, "SELECT * FROM t WHERE a = :a;\n", None)
+            .unwrap();
+        assert_eq!(parsed.violations, []);
+    }
+
+    #[test]
+    fn test_linter_bind_parameter_styles_unknown() {
+        let config =
+            FluffConfig::from_source("[sqlfluff]\nbind_parameter_styles = colon, dolar\n", None);
+        let error = config.verify_bind_parameter_styles().unwrap();
+        assert!(
+            error
+                .value
+                .starts_with("Unknown bind parameter style 'dolar'.")
+        );
+    }
+
+    #[test]
+    fn test_linter_max_parse_depth() {
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nmax_parse_depth = 30\n", None),
+            None,
+            None,
+            false,
+        );
+        let tables = Tables::default();
+        let sql = format!("SELECT {}1{};\n", "(".repeat(10), ")".repeat(10));
+        let parsed = linter.parse_string(&tables, &sql, None).unwrap();
+
+        assert!(parsed.tree.is_none());
+        assert_eq!(parsed.violations.len(), 1);
+        assert!(
+            parsed.violations[0]
+                .description
+                .starts_with("Maximum parse depth of 30 exceeded.")
+        );
+    }
+
+    #[test]
+    fn test_linter_default_max_parse_depth() {
+        let linter = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            false,
+        );
+        let tables = Tables::default();
+        // Deep enough to overflow the stack of a test thread if left unchecked.
+        let sql = format!("SELECT {}1{};\n", "(".repeat(1000), ")".repeat(1000));
+        let parsed = linter.parse_string(&tables, &sql, None).unwrap();
+
+        assert!(parsed.tree.is_none());
+        assert_eq!(parsed.violations.len(), 1);
+        assert!(
+            parsed.violations[0]
+                .description
+                .starts_with("Maximum parse depth of 255 exceeded.")
+        );
+    }
+
+    #[test]
+    fn test_linter_max_parse_matches_per_statement() {
+        let sql = (0..1000)
+            .map(|i| {
+                format!(
+                    "SELECT a{i}, SUM(b) AS total, CASE WHEN c > {i} THEN 1 ELSE 0 END AS flag \
+                     FROM schema_{i}.table_{i} AS t INNER JOIN other AS o ON t.id = o.id \
+                     WHERE t.x = {i} AND o.y IN (1, 2, 3) GROUP BY 1, 3 ORDER BY 1;\n"
+                )
+            })
+            .join("");
+        let tables = Tables::default();
+
+        // The file as a whole takes well over a million matches, but the budget
+        // applies to each statement separately.
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nmax_parse_matches = 100000\n", None),
+            None,
+            None,
+            false,
+        );
+        let parsed = linter.parse_string(&tables, &sql, None).unwrap();
+        assert!(parsed.tree.is_some());
+        assert_eq!(parsed.violations, []);
+
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nmax_parse_matches = 100\n", None),
+            None,
+            None,
+            false,
+        );
+        let parsed = linter.parse_string(&tables, &sql, None).unwrap();
+        assert!(parsed.tree.is_none());
+        assert!(
+            parsed.violations[0]
+                .description
+                .starts_with("Parsing gave up after 100 attempted matches.")
+        );
+    }
+
     // test__linter__mask_templated_violations
     // test__linter__encoding
     // test_delayed_exception
@@ -849,6 +1368,430 @@ mod tests {
         let _parsed = linter.parse_string(&tables, &sql, None).unwrap();
     }
 
+    #[test]
+    fn test_linter_bom_and_crlf_round_trip() {
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nrules = CP01\n", None),
+            None,
+            None,
+            false,
+        );
+        let linted = linter.lint_string("\u{feff}SELECT a\r\nfrom t\r\n", None, true);
+
+        let violations = linted.get_violations(None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line_no, 2);
+        assert_eq!(linted.fix_string(), "\u{feff}SELECT a\r\nFROM t\r\n");
+    }
+
+    #[test]
+    fn test_linter_mixed_line_endings_round_trip() {
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nrules = CP01, LT12\n", None),
+            None,
+            None,
+            false,
+        );
+
+        // Each line keeps its own ending, including the one within the string.
+        let linted = linter.lint_string("SELECT a\r\nfrom t\nwhere b = 'x\r\ny'\r\n", None, true);
+        assert_eq!(
+            linted.fix_string(),
+            "SELECT a\r\nFROM t\nWHERE b = 'x\r\ny'\r\n"
+        );
+
+        // Added line breaks follow the first line break of the file.
+        let linted = linter.lint_string("SELECT a\r\nfrom t\nwhere b", None, true);
+        assert_eq!(linted.fix_string(), "SELECT a\r\nFROM t\nWHERE b\r\n");
+    }
+
+    #[test]
+    fn test_linter_source_patches_mixed_line_endings() {
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nrules = CP01\n", None),
+            None,
+            None,
+            false,
+        );
+        let sql = "\u{feff}SELECT a\nfrom t\r\nwhere b\r\n";
+        let linted = linter.lint_string(sql, None, true);
+
+        let patches = linted.source_patches();
+        assert_eq!(
+            patches
+                .iter()
+                .map(|patch| (patch.start, patch.end, patch.replacement.as_str()))
+                .collect_vec(),
+            [(12, 25, "FROM t\r\nWHERE")]
+        );
+
+        let mut patched = sql.to_string();
+        for patch in patches.iter().rev() {
+            patched.replace_range(patch.start..patch.end, &patch.replacement);
+        }
+        assert_eq!(patched, linted.fix_string());
+    }
+
+    #[test]
+    fn test_linter_fix_does_not_copy_templated_code() {
+        let linter = Linter::new(
+            FluffConfig::from_source(
+                "[sqlfluff]\ntemplater = placeholder\nrules = AL07\n\
+                 ignore_templated_areas = False\n\n[sqlfluff:templater:placeholder]\n\
+                 param_style = colon\ntbl = users\n\n[sqlfluff:rules:aliasing.forbid]\n\
+                 force_enable = True\n",
+                None,
+            ),
+            None,
+            None,
+            false,
+        );
+        let sql = "SELECT u.id FROM :tbl AS u\n";
+
+        let linted = linter.lint_string(sql, None, true);
+        assert_eq!(linted.get_violations(None).len(), 1);
+        assert_eq!(linted.fix_string(), sql);
+    }
+
+    #[test]
+    fn test_linter_unsafe_fixes() {
+        let config = FluffConfig::from_source("[sqlfluff]\nrules = ST07\n", None);
+        let sql = "SELECT a FROM t JOIN u USING (id)\n";
+
+        let linted = Linter::new(config.clone(), None, None, false).lint_string(sql, None, true);
+        assert_eq!(
+            linted.fix_string(),
+            "SELECT a FROM t JOIN u ON t.id = u.id\n"
+        );
+
+        let linted = Linter::new(config, None, None, false)
+            .unsafe_fixes(false)
+            .lint_string(sql, None, true);
+        assert_eq!(linted.get_violations(Some(false)).len(), 1);
+        assert_eq!(linted.fix_string(), sql);
+    }
+
+    #[test]
+    fn test_linter_formatting_only() {
+        let linter = Linter::new(FluffConfig::default(), None, None, false).formatting_only();
+
+        assert!(!linter.rules().is_empty());
+        assert!(
+            linter
+                .rules()
+                .iter()
+                .all(|rule| { rule.code().starts_with("LT") || rule.code().starts_with("CP") })
+        );
+
+        let linted = linter.lint_string("select a  from t as t\n", None, true);
+        assert_eq!(linted.fix_string(), "select a from t as t\n");
+    }
+
+    #[test]
+    fn test_linter_fixable_rules() {
+        let linter = Linter::new(FluffConfig::default(), None, None, false)
+            .fixable_rules(&["CP".to_string()])
+            .unwrap();
+
+        let linted = linter.lint_string("SELECT a  from t\n", None, true);
+        let unfixable = linted
+            .get_violations(Some(false))
+            .into_iter()
+            .map(|violation| violation.rule.unwrap().code)
+            .collect::<Vec<_>>();
+        assert_eq!(unfixable, ["LT01"]);
+        assert_eq!(linted.fix_string(), "SELECT a  FROM t\n");
+
+        assert_eq!(
+            Linter::new(FluffConfig::default(), None, None, false)
+                .fixable_rules(&["XX".to_string()])
+                .err(),
+            Some("No rules match 'XX'".to_string())
+        );
+    }
+
+    /// Appends to every identifier, so its fixes never converge.
+    #[derive(Debug, Clone)]
+    struct RuleGrow;
+
+    impl Rule for RuleGrow {
+        fn load_from_config(
+            &self,
+            _config: &AHashMap<String, Value>,
+        ) -> Result<ErasedRule, String> {
+            Ok(RuleGrow.erased())
+        }
+
+        fn name(&self) -> &'static str {
+            "test.grow"
+        }
+
+        fn description(&self) -> &'static str {
+            "Identifiers are never long enough."
+        }
+
+        fn long_description(&self) -> &'static str {
+            ""
+        }
+
+        fn groups(&self) -> &'static [RuleGroups] {
+            &[RuleGroups::All]
+        }
+
+        fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+            let grown = SegmentBuilder::token(
+                context.tables.next_id(),
+                &format!("{}a", context.segment.raw()),
+                SyntaxKind::NakedIdentifier,
+            )
+            .finish();
+
+            vec![LintResult::new(
+                context.segment.clone().into(),
+                vec![LintFix::replace(context.segment.clone(), vec![grown], None)],
+                None,
+                None,
+            )]
+        }
+
+        fn is_fixable(&self) -> bool {
+            true
+        }
+
+        fn crawl_behaviour(&self) -> Crawler {
+            SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::NakedIdentifier]) })
+                .into()
+        }
+    }
+
+    #[test]
+    fn test_linter_fix_runaway_limit() {
+        let linter = Linter::new(
+            FluffConfig::from_source("[sqlfluff]\nrunaway_limit = 3\n", None),
+            None,
+            None,
+            false,
+        );
+        linter.rules.set(vec![RuleGrow.erased()]).unwrap();
+
+        let linted = linter.lint_string("SELECT a\n", None, true);
+        let violations = linted.get_violations(None);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[1].description,
+            format!(
+                "Loop limit on fixes reached [3]. Fixes for {} did not converge.",
+                RuleGrow.code()
+            )
+        );
+        assert_eq!(violations[1].rule_code(), RuleGrow.code());
+        assert!(!violations[1].fixable);
+        assert_eq!(linted.fix_string(), "SELECT aaaa\n");
+    }
+
+    /// Brackets the contents of every select clause element, optionally
+    /// renaming `a` to `b` inside them with a second, overlapping fix. With
+    /// `rename_bracketed` the rename is also reported for elements which are
+    /// already bracketed.
+    #[derive(Debug, Clone)]
+    struct RuleBracket {
+        rename: bool,
+        rename_bracketed: bool,
+    }
+
+    impl Rule for RuleBracket {
+        fn load_from_config(
+            &self,
+            _config: &AHashMap<String, Value>,
+        ) -> Result<ErasedRule, String> {
+            Ok(self.clone().erased())
+        }
+
+        fn name(&self) -> &'static str {
+            "test.bracket"
+        }
+
+        fn description(&self) -> &'static str {
+            "Select clause elements need brackets."
+        }
+
+        fn long_description(&self) -> &'static str {
+            ""
+        }
+
+        fn groups(&self) -> &'static [RuleGroups] {
+            &[RuleGroups::All]
+        }
+
+        fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+            let token = |raw: &str, kind| {
+                SegmentBuilder::token(context.tables.next_id(), raw, kind).finish()
+            };
+
+            let bracketed = context.segment.raw().starts_with('(');
+            if bracketed && !self.rename_bracketed {
+                return Vec::new();
+            }
+
+            let mut fixes = Vec::new();
+            if !bracketed {
+                let inner = context.segment.segments()[0].clone();
+                fixes.push(LintFix::replace(
+                    inner.clone(),
+                    vec![
+                        token("(", SyntaxKind::StartBracket),
+                        inner,
+                        token(")", SyntaxKind::EndBracket),
+                    ],
+                    None,
+                ));
+            }
+
+            if self.rename {
+                fixes.extend(
+                    context
+                        .segment
+                        .get_raw_segments()
+                        .into_iter()
+                        .filter(|segment| segment.raw() == "a")
+                        .map(|segment| {
+                            LintFix::replace(
+                                segment,
+                                vec![token("b", SyntaxKind::NakedIdentifier)],
+                                None,
+                            )
+                        }),
+                );
+            }
+
+            if fixes.is_empty() {
+                return Vec::new();
+            }
+
+            vec![LintResult::new(
+                context.segment.clone().into(),
+                fixes,
+                None,
+                None,
+            )]
+        }
+
+        fn is_fixable(&self) -> bool {
+            true
+        }
+
+        fn crawl_behaviour(&self) -> Crawler {
+            SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
+                .into()
+        }
+    }
+
+    /// Renames `a` to `b`.
+    #[derive(Debug, Clone)]
+    struct RuleRename;
+
+    impl Rule for RuleRename {
+        fn load_from_config(
+            &self,
+            _config: &AHashMap<String, Value>,
+        ) -> Result<ErasedRule, String> {
+            Ok(RuleRename.erased())
+        }
+
+        fn name(&self) -> &'static str {
+            "test.rename"
+        }
+
+        fn description(&self) -> &'static str {
+            "Identifiers can't be called `a`."
+        }
+
+        fn long_description(&self) -> &'static str {
+            ""
+        }
+
+        fn groups(&self) -> &'static [RuleGroups] {
+            &[RuleGroups::All]
+        }
+
+        fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+            if context.segment.raw() != "a" {
+                return Vec::new();
+            }
+
+            let renamed =
+                SegmentBuilder::token(context.tables.next_id(), "b", SyntaxKind::NakedIdentifier)
+                    .finish();
+
+            vec![LintResult::new(
+                context.segment.clone().into(),
+                vec![LintFix::replace(
+                    context.segment.clone(),
+                    vec![renamed],
+                    None,
+                )],
+                None,
+                None,
+            )]
+        }
+
+        fn is_fixable(&self) -> bool {
+            true
+        }
+
+        fn crawl_behaviour(&self) -> Crawler {
+            SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::NakedIdentifier]) })
+                .into()
+        }
+    }
+
+    #[test]
+    fn test_linter_fix_overlapping_spans() {
+        let linter = |rules: Vec<ErasedRule>| {
+            let linter = Linter::new(FluffConfig::default(), None, None, false);
+            linter.rules.set(rules).unwrap();
+            linter
+        };
+
+        // Two rules editing the same span are applied one after the other.
+        let linted = linter(vec![
+            RuleBracket {
+                rename: false,
+                rename_bracketed: false,
+            }
+            .erased(),
+            RuleRename.erased(),
+        ])
+        .lint_string("SELECT a FROM t\n", None, true);
+        assert_eq!(linted.fix_string(), "SELECT (b) FROM t\n");
+
+        // The rename overlaps the bracketing from the same rule, so it's held back
+        // and retried once the brackets are in. The rule has nothing left to
+        // report by then, so the rename is only applied if it was kept.
+        let linted = linter(vec![
+            RuleBracket {
+                rename: true,
+                rename_bracketed: false,
+            }
+            .erased(),
+        ])
+        .lint_string("SELECT a FROM t\n", None, true);
+        assert_eq!(linted.fix_string(), "SELECT (b) FROM t\n");
+
+        // Here the rule reports the rename again once the brackets are in, so the
+        // held back fix and the new one are the same edit, which is made once.
+        let linted = linter(vec![
+            RuleBracket {
+                rename: true,
+                rename_bracketed: true,
+            }
+            .erased(),
+        ])
+        .lint_string("SELECT a FROM t\n", None, true);
+        assert_eq!(linted.violations.len(), 1);
+        assert_eq!(linted.fix_string(), "SELECT (b) FROM t\n");
+    }
+
     #[test]
     fn test_normalise_newlines() {
         let in_str = "SELECT\r\n foo\n FROM \r \n\r bar;";
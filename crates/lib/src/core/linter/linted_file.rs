@@ -1,5 +1,6 @@
 use std::ops::Range;
 
+use crate::core::linter::common::SourceFormat;
 use crate::core::rules::noqa::IgnoreMask;
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
@@ -7,6 +8,15 @@ use sqruff_lib_core::errors::{SQLBaseError, SqlError};
 use sqruff_lib_core::parser::segments::fix::FixPatch;
 use sqruff_lib_core::templaters::base::{RawFileSlice, TemplatedFile};
 
+/// A fix to the raw file, replacing the bytes in `start..end` of the original
+/// source with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourcePatch {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
 #[derive(Debug, Default)]
 pub struct LintedFile {
     pub path: String,
@@ -14,6 +24,7 @@ pub struct LintedFile {
     pub templated_file: TemplatedFile,
     pub violations: Vec<SQLBaseError>,
     pub ignore_mask: Option<IgnoreMask>,
+    pub source_format: SourceFormat,
 }
 
 impl LintedFile {
@@ -36,10 +47,13 @@ impl LintedFile {
     ///  fail because we rely on patches having a corresponding
     ///  slice of exactly the right file in the list of file
     ///  slices.
+    ///
+    ///  Line endings are restored with `source_format` as the string is built.
     pub fn build_up_fixed_source_string(
         source_file_slices: &[Range<usize>],
         source_patches: &[FixPatch],
         raw_source_string: &str,
+        source_format: &SourceFormat,
     ) -> String {
         // Iterate through the patches, building up the new string.
         let mut str_buff = String::new();
@@ -48,19 +62,69 @@ impl LintedFile {
             let mut is_patched = false;
             for patch in source_patches.iter() {
                 if patch.source_slice == *source_slice {
-                    str_buff.push_str(&patch.fixed_raw);
+                    str_buff.push_str(&source_format.restore_replacement(
+                        raw_source_string,
+                        source_slice.clone(),
+                        &patch.fixed_raw,
+                    ));
                     is_patched = true;
                     break;
                 }
             }
             if !is_patched {
                 // Use the raw string
-                str_buff.push_str(&raw_source_string[source_slice.start..source_slice.end]);
+                str_buff.push_str(
+                    &source_format.restore_slice(raw_source_string, source_slice.clone()),
+                );
             }
         }
         str_buff
     }
 
+    /// The raw source of the file, as it was before templating.
+    pub fn raw_source(&self) -> String {
+        self.source_format.restore(&self.templated_file.source_str)
+    }
+
+    /// The fixes which [`LintedFile::fix_string`] applies, as sorted and
+    /// non-overlapping byte ranges of [`LintedFile::raw_source`].
+    pub fn source_patches(&self) -> Vec<SourcePatch> {
+        let source_str = &self.templated_file.source_str;
+        let source_patches =
+            Self::generate_source_patches(self.patches.clone(), &self.templated_file);
+        let slice_buff = Self::slice_source_file_using_patches(
+            source_patches.clone(),
+            self.templated_file.source_only_slices(),
+            source_str,
+        );
+
+        source_patches
+            .into_iter()
+            .filter(|patch| {
+                slice_buff.contains(&patch.source_slice)
+                    && source_str[patch.source_slice.clone()] != patch.fixed_raw
+            })
+            .map(|patch| {
+                // Patches cover whole segments, trim them down to the text which changed.
+                let original = &source_str[patch.source_slice.clone()];
+                let (prefix, suffix) = common_affixes(original, &patch.fixed_raw);
+                let replacement = &patch.fixed_raw[prefix..patch.fixed_raw.len() - suffix];
+                let range = patch.source_slice.start + prefix..patch.source_slice.end - suffix;
+
+                // Offsets are into the normalised source, map them back to the raw file.
+                SourcePatch {
+                    start: self.source_format.raw_offset(range.start),
+                    end: self.source_format.raw_offset(range.end),
+                    replacement: self.source_format.restore_replacement(
+                        source_str,
+                        range,
+                        replacement,
+                    ),
+                }
+            })
+            .collect()
+    }
+
     pub fn fix_string(self) -> String {
         // Generate patches from the fixed tree. In the process we sort
         // and deduplicate them so that the resultant list is in the
@@ -80,11 +144,18 @@ impl LintedFile {
             &self.templated_file.source_str,
         );
 
-        Self::build_up_fixed_source_string(
+        let mut fixed = Self::build_up_fixed_source_string(
             &slice_buff,
             &filtered_source_patches,
             &self.templated_file.source_str,
-        )
+            &self.source_format,
+        );
+
+        if self.source_format.bom {
+            fixed.insert(0, '\u{feff}');
+        }
+
+        fixed
     }
 
     fn generate_source_patches(
@@ -184,6 +255,29 @@ impl LintedFile {
     }
 }
 
+/// The lengths in bytes of the longest common prefix of `a` and `b`, and of the
+/// longest common suffix of what remains.
+pub(crate) fn common_affixes(a: &str, b: &str) -> (usize, usize) {
+    let prefix = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((idx, _), _)| idx);
+    // Only whole characters are shared, so `prefix` is a boundary in both.
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+
+    let suffix = a
+        .char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((idx, ch), _)| {
+            a.len() - idx - ch.len_utf8()
+        });
+
+    (prefix, suffix)
+}
+
 #[cfg(test)]
 mod test {
     use sqruff_lib_core::templaters::base::TemplatedFileSlice;
@@ -244,6 +338,7 @@ mod test {
                 &source_file_slices,
                 &source_patches,
                 raw_source_string,
+                &SourceFormat::default(),
             );
 
             assert_eq!(result, expected_result)
@@ -450,4 +545,20 @@ mod test {
         )
         .unwrap()
     }
+
+    #[test]
+    fn test_common_affixes() {
+        let test_cases = [
+            ("abc", "abc", (3, 0)),
+            ("select a  from t", "select a from t", (9, 6)),
+            ("a=1", "a = 1", (1, 1)),
+            ("", "x", (0, 0)),
+            ("aa", "aaa", (2, 0)),
+            ("é=1", "é = 1", (2, 1)),
+        ];
+
+        for (a, b, expected) in test_cases {
+            assert_eq!(common_affixes(a, b), expected, "{a:?} -> {b:?}");
+        }
+    }
 }
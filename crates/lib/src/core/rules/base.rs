@@ -23,6 +23,8 @@ pub struct LintResult {
     pub fixes: Vec<LintFix>,
     description: Option<String>,
     source: String,
+    /// Report the violation as a warning rather than an error.
+    pub warning: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Copy, Hash, Eq, AsRefStr)]
@@ -53,6 +55,7 @@ impl LintResult {
             fixes,
             description,
             source: source.unwrap_or_default(),
+            warning: false,
         }
     }
 
@@ -64,14 +67,15 @@ impl LintResult {
             .clone()
             .unwrap_or_else(|| rule.description().to_string());
 
-        let is_fixable = rule.is_fix_compatible();
+        let is_fixable = rule.is_fixable();
 
         SQLLintError::new(description.as_str(), anchor, is_fixable, fixes)
             .config(|this| {
                 this.rule = Some(ErrorStructRule {
                     name: rule.name(),
                     code: rule.code(),
-                })
+                });
+                this.warning = self.warning;
             })
             .into()
     }
@@ -166,7 +170,21 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static + Send + Sync
 
     fn eval(&self, rule_cx: &RuleContext) -> Vec<LintResult>;
 
-    fn is_fix_compatible(&self) -> bool {
+    /// Whether the rule can fix the errors it reports. Fixable rules are run
+    /// again after each round of fixes, until the file stops changing.
+    fn is_fixable(&self) -> bool;
+
+    /// Whether the rule's fixes can't change what the query means, e.g. because
+    /// they only change whitespace or casing, or spell out a default such as
+    /// `UNION DISTINCT`. Other fixes rewrite the query, e.g. `USING` to `ON`,
+    /// so `sqruff fix` only applies them when asked to.
+    fn has_safe_fixes(&self) -> bool {
+        false
+    }
+
+    /// Whether the rule should still report errors within templated code when
+    /// `ignore_templated_areas` is enabled.
+    fn targets_templated(&self) -> bool {
         false
     }
 
@@ -182,6 +200,10 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static + Send + Sync
     ) -> Vec<SQLLintError> {
         let mut root_context = RuleContext::new(tables, dialect, config, tree.clone());
         let mut vs = Vec::new();
+        let ignore_templated_areas = config
+            .get("ignore_templated_areas", "core")
+            .as_bool()
+            .unwrap_or(true);
 
         // TODO Will to return a note that rules were skipped
         if self.dialect_skip().contains(&dialect.name) && !self.force_enable() {
@@ -206,7 +228,12 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static + Send + Sync
                 // Assume this means no problems (also means no memory)
             } else {
                 for elem in resp {
-                    self.process_lint_result(elem, templated_file, &mut new_lerrs);
+                    self.process_lint_result(
+                        elem,
+                        templated_file,
+                        ignore_templated_areas,
+                        &mut new_lerrs,
+                    );
                 }
             }
 
@@ -219,16 +246,23 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static + Send + Sync
 
     fn process_lint_result(
         &self,
-        res: LintResult,
+        mut res: LintResult,
         templated_file: &TemplatedFile,
+        ignore_templated_areas: bool,
         new_lerrs: &mut Vec<SQLLintError>,
     ) {
+        // Fixes which would touch templated code are never applied. The error
+        // itself is dropped too, unless templated areas aren't being ignored.
         if res
             .fixes
             .iter()
             .any(|it| it.has_template_conflicts(templated_file))
         {
-            return;
+            if ignore_templated_areas {
+                return;
+            }
+
+            res.fixes.clear();
         }
 
         if let Some(lerr) = res.to_linting_error(self.erased(), res.fixes.clone()) {
@@ -4,6 +4,7 @@ use std::rc::Rc;
 
 use ahash::AHashMap;
 use sqruff_lib_core::dialects::base::Dialect;
+use sqruff_lib_core::dialects::syntax::SyntaxSet;
 use sqruff_lib_core::parser::segments::base::{ErasedSegment, Tables};
 use sqruff_lib_core::templaters::base::TemplatedFile;
 
@@ -83,6 +84,30 @@ impl<'a> RuleContext<'a> {
         self.memory.borrow_mut().insert(id, Box::new(value));
     }
 
+    /// The direct parent of `segment`, if it isn't the root.
+    pub fn parent(&self) -> Option<&ErasedSegment> {
+        self.parent_stack.last()
+    }
+
+    /// The ancestors of `segment`, nearest first.
+    pub fn ancestors(&self) -> impl Iterator<Item = &ErasedSegment> {
+        self.parent_stack.iter().rev()
+    }
+
+    /// The nearest ancestor of `segment` whose type is in `types`.
+    pub fn ancestor_of_type(&self, types: &SyntaxSet) -> Option<&ErasedSegment> {
+        self.ancestors()
+            .find(|ancestor| types.contains(ancestor.get_type()))
+    }
+
+    pub fn siblings_pre(&self) -> Vec<ErasedSegment> {
+        if !self.parent_stack.is_empty() {
+            self.parent_stack.last().unwrap().segments()[..self.segment_idx].to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
     pub fn siblings_post(&self) -> Vec<ErasedSegment> {
         if !self.parent_stack.is_empty() {
             self.parent_stack.last().unwrap().segments()[self.segment_idx + 1..].to_vec()
@@ -91,3 +116,86 @@ impl<'a> RuleContext<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+    use sqruff_lib_core::parser::segments::base::{ErasedSegment, Tables};
+
+    use super::RuleContext;
+    use crate::core::config::FluffConfig;
+    use crate::core::linter::core::Linter;
+    use crate::core::rules::crawlers::{BaseCrawler, SegmentSeekerCrawler};
+
+    fn code_raws(segments: &[ErasedSegment]) -> Vec<String> {
+        segments
+            .iter()
+            .filter(|segment| segment.is_code())
+            .map(|segment| segment.raw().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_context_ancestors_and_siblings() {
+        let config = FluffConfig::new(<_>::default(), None, None);
+        let linter = Linter::new(config.clone(), None, None, false);
+        let tables = Tables::default();
+        let parsed = linter
+            .parse_string(&tables, "SELECT a, b FROM t", None)
+            .unwrap();
+        let tree = parsed.tree.unwrap();
+
+        let mut context = RuleContext::new(&tables, config.get_dialect(), &config, tree);
+        let mut seen = Vec::new();
+        SegmentSeekerCrawler::new(const { SyntaxSet::single(SyntaxKind::SelectClauseElement) })
+            .crawl(&mut context, &mut |context| {
+                seen.push((
+                    context.segment.raw().to_string(),
+                    context.parent().map(|parent| parent.get_type()),
+                    context
+                        .ancestors()
+                        .map(|ancestor| ancestor.get_type())
+                        .collect_vec(),
+                    context
+                        .ancestor_of_type(const { &SyntaxSet::single(SyntaxKind::SelectStatement) })
+                        .map(|ancestor| ancestor.raw().to_string()),
+                    context
+                        .ancestor_of_type(const { &SyntaxSet::single(SyntaxKind::FromClause) })
+                        .is_some(),
+                    code_raws(&context.siblings_pre()),
+                    code_raws(&context.siblings_post()),
+                ));
+            });
+
+        let ancestors = vec![
+            SyntaxKind::SelectClause,
+            SyntaxKind::SelectStatement,
+            SyntaxKind::Statement,
+            SyntaxKind::File,
+        ];
+        assert_eq!(
+            seen,
+            [
+                (
+                    "a".to_string(),
+                    Some(SyntaxKind::SelectClause),
+                    ancestors.clone(),
+                    Some("SELECT a, b FROM t".to_string()),
+                    false,
+                    vec!["SELECT".to_string()],
+                    vec![",".to_string(), "b".to_string()],
+                ),
+                (
+                    "b".to_string(),
+                    Some(SyntaxKind::SelectClause),
+                    ancestors,
+                    Some("SELECT a, b FROM t".to_string()),
+                    false,
+                    vec!["SELECT".to_string(), "a".to_string(), ",".to_string()],
+                    Vec::new(),
+                ),
+            ]
+        );
+    }
+}
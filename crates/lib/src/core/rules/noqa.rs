@@ -67,6 +67,7 @@ impl NoQADirective {
                         rule: None,
                         source_slice: Default::default(),
                         fixable: false,
+                        in_templated_region: false,
                     });
                 }
             }
@@ -136,6 +137,7 @@ impl NoQADirective {
                                     rule: None,
                                     source_slice: Default::default(),
                                     fixable: false,
+                                    in_templated_region: false,
                                 })
                             } else {
                                 Ok(Some(NoQADirective::RangeIgnoreRules(RangeIgnoreRules {
@@ -175,6 +177,7 @@ impl NoQADirective {
                                     rule: None,
                                     source_slice: Default::default(),
                                     fixable: false,
+                                    in_templated_region: false,
                                 })
                             } else {
                                 Ok(Some(NoQADirective::RangeIgnoreRules(RangeIgnoreRules {
@@ -201,6 +204,7 @@ impl NoQADirective {
                                 rule: None,
                                 source_slice: Default::default(),
                                 fixable: false,
+                                in_templated_region: false,
                             })
                         } else {
                             return Ok(Some(NoQADirective::LineIgnoreRules(LineIgnoreRules {
@@ -223,6 +227,7 @@ impl NoQADirective {
                             rule: None,
                             source_slice: Default::default(),
                             fixable: false,
+                            in_templated_region: false,
                         })
                     }
                 } else {
@@ -238,6 +243,7 @@ impl NoQADirective {
                         rule: None,
                         source_slice: Default::default(),
                         fixable: false,
+                        in_templated_region: false,
                     })
                 }
             } else {
@@ -324,6 +330,7 @@ impl IgnoreMask {
                 rule: None,
                 source_slice: Default::default(),
                 fixable: false,
+                in_templated_region: false,
             })?
             .source_position();
         NoQADirective::parse_from_comment(comment_content, line_no, line_pos)
@@ -492,6 +499,7 @@ mod tests {
             }),
             source_slice: Default::default(),
             fixable: true,
+            in_templated_region: false,
         };
         let mask = IgnoreMask {
             ignore_list: vec![NoQADirective::LineIgnoreRules(LineIgnoreRules {
@@ -162,7 +162,12 @@ FROM foo AS voo
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
+        // Only ever adds or removes the `AS` keyword.
         true
     }
 
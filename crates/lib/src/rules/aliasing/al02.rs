@@ -43,7 +43,12 @@ impl Rule for RuleAL02 {
         Ok(rule.erased())
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
+        // Only ever adds or removes the `AS` keyword.
         true
     }
 
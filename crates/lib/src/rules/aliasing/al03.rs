@@ -159,6 +159,10 @@ FROM foo
         )]
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
             .into()
@@ -123,6 +123,10 @@ FROM
         )
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
     }
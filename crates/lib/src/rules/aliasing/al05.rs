@@ -137,7 +137,7 @@ FROM foo
         violations
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
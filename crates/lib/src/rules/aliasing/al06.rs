@@ -153,6 +153,10 @@ JOIN
         self.lint_aliases(from_expression_elements.base)
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
     }
@@ -339,7 +339,7 @@ FROM
         )
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -124,6 +124,10 @@ FROM
         violations
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) }).into()
     }
@@ -145,6 +145,10 @@ FROM table;
         violations
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) }).into()
     }
@@ -85,6 +85,10 @@ FROM foo
         Vec::new()
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
     }
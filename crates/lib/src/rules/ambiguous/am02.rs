@@ -110,7 +110,11 @@ SELECT a, b FROM table_2
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
         true
     }
 
@@ -92,7 +92,7 @@ ORDER BY a ASC, b DESC
         )]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -85,6 +85,10 @@ SELECT a, b FROM t
         }
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&START_TYPES) })
             .disallow_recurse()
@@ -159,7 +159,7 @@ SELECT a, b FROM table_2
         vec![]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
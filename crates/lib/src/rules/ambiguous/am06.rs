@@ -167,6 +167,10 @@ ORDER BY a ASC, b DESC
         vec![]
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(
             const {
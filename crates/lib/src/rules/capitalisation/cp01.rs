@@ -163,7 +163,11 @@ from foo
         )]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
         true
     }
 
@@ -106,7 +106,11 @@ FROM foo
         self.base.eval(context)
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
         true
     }
 
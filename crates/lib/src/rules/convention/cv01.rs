@@ -167,7 +167,12 @@ SELECT * FROM X WHERE 1 != 2 AND 3 != 4;
         )]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
+        // `!=` and `<>` mean the same thing.
         true
     }
 
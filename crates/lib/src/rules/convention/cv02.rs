@@ -120,6 +120,10 @@ FROM baz;
         )]
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::FunctionNameIdentifier]) })
             .into()
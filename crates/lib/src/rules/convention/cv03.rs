@@ -144,6 +144,10 @@ FROM foo
         Vec::new()
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) }).into()
     }
@@ -167,6 +167,10 @@ from table_a
         Vec::new()
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
     }
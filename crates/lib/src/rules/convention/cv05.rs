@@ -3,12 +3,12 @@ use std::borrow::Cow;
 use ahash::AHashMap;
 use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
 use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
-use sqruff_lib_core::utils::functional::segments::Segments;
 
 use crate::core::config::Value;
 use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
 use crate::core::rules::context::RuleContext;
 use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::functional::context::FunctionalContext;
 use crate::utils::reflow::sequence::{Filter, ReflowSequence, TargetSide};
 
 #[derive(Debug)]
@@ -78,49 +78,39 @@ WHERE a IS NULL
     }
 
     fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
-        if context.parent_stack.len() >= 2 {
+        if let Some(grandparent) = context.ancestors().nth(1) {
             for type_str in [
                 SyntaxKind::SetClauseList,
                 SyntaxKind::ExecuteScriptStatement,
                 SyntaxKind::OptionsSegment,
             ] {
-                if context.parent_stack[context.parent_stack.len() - 2].is_type(type_str) {
+                if grandparent.is_type(type_str) {
                     return Vec::new();
                 }
             }
         }
 
-        if !context.parent_stack.is_empty() {
+        if let Some(parent) = context.parent() {
             for type_str in [
                 SyntaxKind::SetClauseList,
                 SyntaxKind::ExecuteScriptStatement,
                 SyntaxKind::AssignmentOperator,
+                SyntaxKind::ExclusionConstraintElement,
             ] {
-                if context.parent_stack[context.parent_stack.len() - 1].is_type(type_str) {
+                if parent.is_type(type_str) {
                     return Vec::new();
                 }
             }
         }
 
-        if !context.parent_stack.is_empty()
-            && context.parent_stack[context.parent_stack.len() - 1]
-                .is_type(SyntaxKind::ExclusionConstraintElement)
-        {
-            return Vec::new();
-        }
-
         let raw_consist = context.segment.raw();
         if !["=", "!=", "<>"].contains(&raw_consist.as_str()) {
             return Vec::new();
         }
 
-        let segment = context.parent_stack.last().unwrap().segments().to_vec();
-
-        let siblings = Segments::from_vec(segment, None);
-        let after_op_list =
-            siblings.select::<fn(&ErasedSegment) -> bool>(None, None, Some(&context.segment), None);
-
-        let next_code = after_op_list.find_first(Some(|sp: &ErasedSegment| sp.is_code()));
+        let next_code = FunctionalContext::new(context)
+            .siblings_post()
+            .find_first(Some(|sp: &ErasedSegment| sp.is_code()));
 
         if !next_code.all(Some(|it| it.is_type(SyntaxKind::NullLiteral))) {
             return Vec::new();
@@ -163,6 +153,10 @@ WHERE a IS NULL
         )]
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::ComparisonOperator]) })
             .into()
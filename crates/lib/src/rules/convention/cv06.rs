@@ -92,7 +92,7 @@ FROM foo;
         results
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -124,7 +124,7 @@ Don’t wrap top-level statements in brackets.
         results
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
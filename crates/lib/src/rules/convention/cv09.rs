@@ -140,6 +140,10 @@ CREATE TABLE myschema.t1 (a BOOL);
         vec![]
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         TokenSeekerCrawler.into()
     }
@@ -162,7 +162,7 @@ from foo
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -404,7 +404,7 @@ FROM foo;
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
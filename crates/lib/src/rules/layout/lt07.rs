@@ -130,8 +130,12 @@ SELECT * FROM zoo
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
-        false
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
+        true
     }
 
     fn crawl_behaviour(&self) -> Crawler {
@@ -173,7 +173,11 @@ Add trailing newline to the end. The $ character represents end of file.
         }
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
         true
     }
 
@@ -122,7 +122,11 @@ Start file on either code or comment. (The ^ represents the beginning of the fil
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
+    fn has_safe_fixes(&self) -> bool {
         true
     }
 
@@ -99,6 +99,10 @@ LEFT JOIN vee ON vee.a = foo.a
         self.base.eval(context)
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
     }
@@ -380,7 +380,7 @@ FROM foo
         )
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -1,6 +1,10 @@
+use std::str::FromStr;
+
 use itertools::Itertools;
 use regex::Regex;
+use sqruff_lib_core::dialects::base::KeywordClass;
 use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use strum_macros::{AsRefStr, EnumString};
 
 use crate::core::rules::base::{CloneRule, ErasedRule, LintResult, Rule, RuleGroups};
 use crate::core::rules::context::RuleContext;
@@ -13,6 +17,28 @@ pub struct RuleRF04 {
     quoted_identifiers_policy: Option<String>,
     ignore_words: Vec<String>,
     ignore_words_regex: Vec<Regex>,
+    reserved_keywords_severity: Severity,
+    unreserved_keywords_severity: Severity,
+}
+
+/// How an identifier colliding with a given keyword class is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, AsRefStr, EnumString)]
+#[strum(serialize_all = "lowercase")]
+enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Ignore,
+}
+
+fn severity_from_config(
+    config: &ahash::AHashMap<String, crate::core::config::Value>,
+    key: &str,
+) -> Result<Severity, String> {
+    let severity = config[key].as_string().unwrap_or_default();
+    Severity::from_str(severity).map_err(|_| {
+        format!("Rule RF04 expects `{key}` to be one of error, warning or ignore. Got: {severity}")
+    })
 }
 
 impl Rule for RuleRF04 {
@@ -45,6 +71,11 @@ impl Rule for RuleRF04 {
                         .collect_vec()
                 })
                 .unwrap_or_default(),
+            reserved_keywords_severity: severity_from_config(config, "reserved_keywords_severity")?,
+            unreserved_keywords_severity: severity_from_config(
+                config,
+                "unreserved_keywords_severity",
+            )?,
         }
         .erased())
     }
@@ -98,54 +129,59 @@ FROM foo AS vee
             return vec![LintResult::new(None, Vec::new(), None, None)];
         }
 
-        let raw_segment = context.segment.raw();
-        let upper_segment = {
-            if context.segment.is_type(SyntaxKind::NakedIdentifier) {
-                raw_segment.to_uppercase()
-            } else {
-                raw_segment[1..raw_segment.len() - 1].to_uppercase()
-            }
-        };
-
-        // FIXME: simplify the condition
-        if (context.segment.is_type(SyntaxKind::NakedIdentifier)
-            && identifiers_policy_applicable(
+        let keyword_class = if context.segment.is_type(SyntaxKind::NakedIdentifier) {
+            // Reserved keywords can't be parsed as unquoted identifiers, so only the
+            // unreserved ones can end up here.
+            if identifiers_policy_applicable(
                 &self.unquoted_identifiers_policy,
                 &context.parent_stack,
-            )
-            && context
-                .dialect
-                .sets("unreserved_keywords")
-                .contains(context.segment.raw().to_uppercase().as_str()))
-            || (context.segment.is_type(SyntaxKind::QuotedIdentifier)
-                && self.quoted_identifiers_policy.as_ref().is_some_and(
-                    |quoted_identifiers_policy| {
+            ) {
+                context
+                    .dialect
+                    .keyword_class(context.segment.raw())
+                    .filter(|&class| class == KeywordClass::Unreserved)
+            } else {
+                None
+            }
+        } else {
+            let raw = context.segment.raw();
+            let applicable =
+                self.quoted_identifiers_policy
+                    .as_ref()
+                    .is_some_and(|quoted_identifiers_policy| {
                         identifiers_policy_applicable(
                             quoted_identifiers_policy,
                             &context.parent_stack,
                         )
-                    },
-                )
-                && context
-                    .dialect
-                    .sets("unreserved_keywords")
-                    .contains(upper_segment.as_str())
-                || context
-                    .dialect
-                    .sets("reserved_keywords")
-                    .contains(upper_segment.as_str()))
-        {
-            vec![LintResult::new(
-                Some(context.segment.clone()),
-                Vec::new(),
-                None,
-                None,
-            )]
-        } else {
-            Vec::new()
+                    });
+            if applicable {
+                context.dialect.keyword_class(&raw[1..raw.len() - 1])
+            } else {
+                None
+            }
+        };
+
+        let severity = match keyword_class {
+            Some(KeywordClass::Reserved) => self.reserved_keywords_severity,
+            Some(KeywordClass::Unreserved) => self.unreserved_keywords_severity,
+            None => Severity::Ignore,
+        };
+
+        match severity {
+            Severity::Ignore => Vec::new(),
+            Severity::Error | Severity::Warning => {
+                let mut result =
+                    LintResult::new(Some(context.segment.clone()), Vec::new(), None, None);
+                result.warning = severity == Severity::Warning;
+                vec![result]
+            }
         }
     }
 
+    fn is_fixable(&self) -> bool {
+        false
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(
             const { SyntaxSet::new(&[SyntaxKind::NakedIdentifier, SyntaxKind::QuotedIdentifier]) },
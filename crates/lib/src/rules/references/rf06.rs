@@ -17,6 +17,7 @@ pub struct RuleRF06 {
     ignore_words: Vec<String>,
     ignore_words_regex: Vec<Regex>,
     force_enable: bool,
+    case_sensitive: bool,
 }
 
 impl Rule for RuleRF06 {
@@ -46,6 +47,7 @@ impl Rule for RuleRF06 {
                 })
                 .unwrap_or_default(),
             force_enable: config["force_enable"].as_bool().unwrap(),
+            case_sensitive: config["case_sensitive"].as_bool().unwrap(),
         }
         .erased())
     }
@@ -76,6 +78,8 @@ Use unquoted identifiers where possible.
 SELECT 123 as foo
 ```
 
+In dialects which fold unquoted identifiers to one case (e.g. lowercase in Postgres, uppercase in Snowflake), an identifier is only considered needlessly quoted if it's already in that case, since removing the quotes from `"MyCol"` would reference a different column. Set `case_sensitive = False` to ignore the casing.
+
 When `prefer_quoted_identifiers = True`, the quotes are always necessary, no matter if the identifier is valid, a reserved keyword, or contains special characters.
 
 > **Note**
@@ -139,12 +143,8 @@ SELECT 123 as `foo` -- For BigQuery, MySql, ...
 
         let identifier_is_keyword = context
             .dialect
-            .sets("reserved_keywords")
-            .contains(identifier_contents.to_uppercase().as_str())
-            || context
-                .dialect
-                .sets("unreserved_keywords")
-                .contains(identifier_contents.to_uppercase().as_str());
+            .keyword_class(&identifier_contents)
+            .is_some();
 
         let context_policy = if self.prefer_quoted_identifiers {
             SyntaxKind::NakedIdentifier
@@ -204,6 +204,15 @@ SELECT 123 as `foo` -- For BigQuery, MySql, ...
             )];
         }
 
+        // Removing the quotes would change which object is referenced if the
+        // dialect folds unquoted identifiers to a different case.
+        if self.case_sensitive
+            && fold_unquoted_case(context.dialect.name, &identifier_contents)
+                .is_some_and(|folded| folded != identifier_contents)
+        {
+            return Vec::new();
+        }
+
         let owned = context.dialect.grammar("NakedIdentifierSegment");
 
         let naked_identifier_parser = owned.as_regex().unwrap();
@@ -243,7 +252,7 @@ SELECT 123 as `foo` -- For BigQuery, MySql, ...
         Vec::new()
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -255,6 +264,16 @@ SELECT 123 as `foo` -- For BigQuery, MySql, ...
     }
 }
 
+/// Fold an identifier to the case the dialect stores unquoted identifiers in,
+/// for dialects where quoted identifiers are case sensitive.
+fn fold_unquoted_case(dialect: DialectKind, identifier: &str) -> Option<String> {
+    match dialect {
+        DialectKind::Postgres | DialectKind::Redshift => Some(identifier.to_lowercase()),
+        DialectKind::Snowflake => Some(identifier.to_uppercase()),
+        _ => None,
+    }
+}
+
 fn is_full_match(pattern: &str, text: &str) -> bool {
     let full_pattern = format!("(?i)^{}$", pattern); // Adding (?i) for case insensitivity
     let regex = fancy_regex::Regex::new(&full_pattern).unwrap();
@@ -61,11 +61,21 @@ select
         else fab
     end as fab_clean
 from fancy_table
+
+-- And where the case statement nulls out
+-- a sentinel value.
+
+select
+    case
+        when fab = 0 then null
+        else fab
+    end as fab_clean
+from fancy_table
 ```
 
 **Best practice**
 
-Reduce to WHEN condition within COALESCE function.
+Reduce to WHEN condition within COALESCE or NULLIF function.
 
 ```sql
 select
@@ -82,6 +92,12 @@ from fancy_table
 
 select fab as fab_clean
 from fancy_table
+
+-- Null out a sentinel value.
+
+select
+    nullif(fab, 0) as fab_clean
+from fancy_table
 ```
 "#
     }
@@ -138,8 +154,9 @@ from fancy_table
                             SegmentBuilder::keyword(context.tables.next_id(), "false");
                         let preceding_not = then_expression_upper == "FALSE";
 
-                        let fixes = Self::coalesce_fix_list(
+                        let fixes = Self::function_fix_list(
                             context,
+                            "coalesce",
                             coalesce_arg_1,
                             coalesce_arg_2,
                             preceding_not,
@@ -157,6 +174,32 @@ from fancy_table
                 }
             }
 
+            let nullif_args = else_clauses
+                .children(Some(|it| it.is_type(SyntaxKind::Expression)))
+                .first()
+                .and_then(|else_expression| {
+                    Self::nullif_args(
+                        &context.segment,
+                        &condition_expression,
+                        &then_expression,
+                        else_expression,
+                    )
+                });
+
+            if let Some((nullif_arg_1, nullif_arg_2)) = nullif_args {
+                let fixes =
+                    Self::function_fix_list(context, "nullif", nullif_arg_1, nullif_arg_2, false);
+
+                return vec![LintResult::new(
+                    condition_expression.into(),
+                    fixes,
+                    "Unnecessary CASE statement. Use NULLIF function instead."
+                        .to_owned()
+                        .into(),
+                    None,
+                )];
+            }
+
             let condition_expression_segments_raw: AHashSet<_> = AHashSet::from_iter(
                 condition_expression
                     .segments()
@@ -225,8 +268,13 @@ from fancy_table
                         )];
                     }
 
-                    let fixes =
-                        Self::coalesce_fix_list(context, coalesce_arg_1, coalesce_arg_2, false);
+                    let fixes = Self::function_fix_list(
+                        context,
+                        "coalesce",
+                        coalesce_arg_1,
+                        coalesce_arg_2,
+                        false,
+                    );
 
                     return vec![LintResult::new(
                         condition_expression.into(),
@@ -262,30 +310,82 @@ from fancy_table
         }
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::CaseExpression]) }).into()
     }
 }
 
 impl RuleST02 {
-    fn coalesce_fix_list(
+    /// Matches `WHEN a = b THEN NULL ELSE a`, returning the arguments for
+    /// `NULLIF(a, b)`.
+    fn nullif_args(
+        case_expression: &ErasedSegment,
+        condition_expression: &ErasedSegment,
+        then_expression: &ErasedSegment,
+        else_expression: &ErasedSegment,
+    ) -> Option<(ErasedSegment, ErasedSegment)> {
+        if !then_expression.raw().eq_ignore_ascii_case("NULL") {
+            return None;
+        }
+
+        // In a simple CASE, e.g. `CASE x WHEN a = b`, the condition is compared
+        // against the operand rather than being a condition of its own.
+        let has_operand = case_expression
+            .segments()
+            .iter()
+            .skip(1)
+            .find(|it| it.is_code())
+            .is_some_and(|it| !it.is_type(SyntaxKind::WhenClause));
+        if has_operand {
+            return None;
+        }
+
+        let [lhs, operator, rhs] = condition_expression
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect_vec()[..]
+        else {
+            return None;
+        };
+
+        if !operator.is_type(SyntaxKind::ComparisonOperator) || operator.raw() != "=" {
+            return None;
+        }
+
+        let else_raw = else_expression.raw().to_uppercase_smolstr();
+        if lhs.raw().to_uppercase_smolstr() == else_raw {
+            Some((lhs.clone(), rhs.clone()))
+        } else if rhs.raw().to_uppercase_smolstr() == else_raw {
+            Some((rhs.clone(), lhs.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn function_fix_list(
         context: &RuleContext,
-        coalesce_arg_1: ErasedSegment,
-        coalesce_arg_2: ErasedSegment,
+        function_name: &str,
+        arg_1: ErasedSegment,
+        arg_2: ErasedSegment,
         preceding_not: bool,
     ) -> Vec<LintFix> {
         let mut edits = vec![
             SegmentBuilder::token(
                 context.tables.next_id(),
-                "coalesce",
+                function_name,
                 SyntaxKind::FunctionNameIdentifier,
             )
             .finish(),
             SegmentBuilder::symbol(context.tables.next_id(), "("),
-            coalesce_arg_1,
+            arg_1,
             SegmentBuilder::symbol(context.tables.next_id(), ","),
             SegmentBuilder::whitespace(context.tables.next_id(), " "),
-            coalesce_arg_2,
+            arg_2,
             SegmentBuilder::symbol(context.tables.next_id(), ")"),
         ];
 
@@ -223,7 +223,7 @@ FROM mytable
         vec![LintResult::new(case2.first().cloned(), fixes, None, None)]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
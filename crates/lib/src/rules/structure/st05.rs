@@ -244,7 +244,7 @@ join c using(x)
         lint_results
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -69,7 +69,6 @@ INNER JOIN table_b
     fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
         let functional_context = FunctionalContext::new(context);
         let segment = functional_context.segment();
-        let parent_stack = functional_context.parent_stack();
 
         let usings = segment.children(Some(|it: &ErasedSegment| it.is_keyword("using")));
         let using_anchor = usings.first();
@@ -85,8 +84,8 @@ INNER JOIN table_b
             None,
         );
 
-        let tables_in_join = parent_stack
-            .last()
+        let tables_in_join = context
+            .parent()
             .unwrap()
             .segments()
             .iter()
@@ -103,10 +102,8 @@ INNER JOIN table_b
             return vec![unfixable_result];
         }
 
-        let stmts = parent_stack.find_last(Some(|it: &ErasedSegment| {
-            it.is_type(SyntaxKind::SelectStatement)
-        }));
-        let parent_select = stmts.first();
+        let parent_select =
+            context.ancestor_of_type(const { &SyntaxSet::single(SyntaxKind::SelectStatement) });
 
         let Some(parent_select) = parent_select else {
             return vec![unfixable_result];
@@ -153,7 +150,7 @@ INNER JOIN table_b
         )]
     }
 
-    fn is_fix_compatible(&self) -> bool {
+    fn is_fixable(&self) -> bool {
         true
     }
 
@@ -190,6 +190,10 @@ SELECT DISTINCT a, b FROM foo
         Vec::new()
     }
 
+    fn is_fixable(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(
             const { SyntaxSet::new(&[SyntaxKind::SelectClause, SyntaxKind::Function]) },
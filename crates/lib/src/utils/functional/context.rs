@@ -18,6 +18,13 @@ impl<'a> FunctionalContext<'a> {
         )
     }
 
+    pub fn siblings_pre(&self) -> Segments {
+        Segments::from_vec(
+            self.context.siblings_pre(),
+            self.context.templated_file.clone(),
+        )
+    }
+
     pub fn siblings_post(&self) -> Segments {
         Segments::from_vec(
             self.context.siblings_post(),
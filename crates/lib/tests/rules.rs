@@ -88,6 +88,10 @@ fn main() {
         let input = std::fs::read_to_string(path).unwrap();
 
         let file: TestFile = serde_yaml::from_str(&input).unwrap();
+        let is_fixable = sqruff_lib::rules::rules()
+            .into_iter()
+            .find(|rule| rule.code() == file.rule)
+            .is_some_and(|rule| rule.is_fixable());
         core.get_mut("core").unwrap().as_map_mut().unwrap().insert(
             "rule_allowlist".into(),
             Value::Array(vec![Value::String(file.rule.clone().into())]),
@@ -190,6 +194,11 @@ fn main() {
                     assert_ne!(&f.paths[0].files[0].violations, &[])
                 }
                 TestCaseKind::Fix { fail_str, fix_str } => {
+                    assert!(
+                        is_fixable || fail_str == fix_str,
+                        "{} fixes errors but isn't fixable",
+                        file.rule
+                    );
                     let f = std::mem::take(
                         &mut linter.lint_string_wrapped(&fail_str, None, true).paths[0].files[0],
                     )
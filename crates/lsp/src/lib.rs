@@ -310,3 +310,36 @@ where
         params: serde_json::to_value(&params).unwrap(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::notification::{DidOpenTextDocument, Notification};
+    use lsp_types::{DidOpenTextDocumentParams, TextDocumentItem, Uri};
+
+    use super::LanguageServer;
+
+    #[test]
+    fn test_format_applies_unsafe_fixes() {
+        let mut server = LanguageServer::new(|_| {});
+        let uri: Uri = "file:///query.sql".parse().unwrap();
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "sql".into(),
+                version: 0,
+                text: "SELECT a FROM t\nUNION\nSELECT b FROM u\n".into(),
+            },
+        };
+        server.on_notification(
+            DidOpenTextDocument::METHOD,
+            serde_json::to_value(params).unwrap(),
+        );
+
+        // Only `sqruff fix` holds back fixes which could change the query.
+        let edits = server.format(uri);
+        assert_eq!(
+            edits[0].new_text,
+            "SELECT a FROM t\nUNION DISTINCT\nSELECT b FROM u\n"
+        );
+    }
+}